@@ -1,14 +1,11 @@
 #![feature(portable_simd)]
 #![allow(clippy::excessive_precision)]
-extern crate std_float;
 
 /// Benchmarks game nbody code
 /// Taken from the `packed_simd` crate
 /// Run this benchmark with `cargo test --example nbody`
 mod nbody {
     use core_simd::simd::prelude::*;
-    #[allow(unused)] // False positive?
-    use std_float::StdFloat;
 
     use std::f64::consts::PI;
     const SOLAR_MASS: f64 = 4.0 * PI * PI;
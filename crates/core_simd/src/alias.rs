@@ -1,3 +1,5 @@
+use crate::simd::bf16;
+
 macro_rules! number {
     { 1 } => { "one" };
     { 2 } => { "two" };
@@ -93,6 +95,16 @@ alias! {
         i64x64 64
     }
 
+    i128 = {
+        i128x1 1
+        i128x2 2
+        i128x4 4
+        i128x8 8
+        i128x16 16
+        i128x32 32
+        i128x64 64
+    }
+
     isize = {
         isizex1 1
         isizex2 2
@@ -143,6 +155,16 @@ alias! {
         u64x64 64
     }
 
+    u128 = {
+        u128x1 1
+        u128x2 2
+        u128x4 4
+        u128x8 8
+        u128x16 16
+        u128x32 32
+        u128x64 64
+    }
+
     usize = {
         usizex1 1
         usizex2 2
@@ -153,6 +175,16 @@ alias! {
         usizex64 64
     }
 
+    bf16 = {
+        bf16x1 1
+        bf16x2 2
+        bf16x4 4
+        bf16x8 8
+        bf16x16 16
+        bf16x32 32
+        bf16x64 64
+    }
+
     f16 = {
         f16x1 1
         f16x2 2
@@ -225,6 +257,16 @@ mask_alias! {
         mask64x64 64
     }
 
+    i128 : "128-bit" = {
+        mask128x1 1
+        mask128x2 2
+        mask128x4 4
+        mask128x8 8
+        mask128x16 16
+        mask128x32 32
+        mask128x64 64
+    }
+
     isize : "pointer-sized" = {
         masksizex1 1
         masksizex2 2
@@ -0,0 +1,222 @@
+use crate::simd::Simd;
+use core::{fmt, mem};
+
+/// A 16-bit "brain" floating point number, with the exponent range of [`f32`] and 8 bits of
+/// precision.
+///
+/// This is a storage-only type: vectors of `bf16` can be loaded, stored, and rearranged, but
+/// arithmetic is done by converting to [`f32`] with [`SimdBf16::to_f32`] and back with
+/// [`SimdBf16::from_f32`].
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct bf16(u16);
+
+impl bf16 {
+    /// Raw transmutation from a `u16`.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Raw transmutation to a `u16`.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts an `f32` to the nearest `bf16`, with ties rounding to even.
+    ///
+    /// NaN is converted to a quiet NaN.
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(x: f32) -> Self {
+        let bits = x.to_bits();
+        if x.is_nan() {
+            Self((bits >> 16) as u16 | 0x0040)
+        } else {
+            Self((bits.wrapping_add(0x7fff + ((bits >> 16) & 1)) >> 16) as u16)
+        }
+    }
+
+    /// Converts to an `f32`.  This conversion is exact.
+    #[inline]
+    #[must_use]
+    pub const fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+impl fmt::Debug for bf16 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+/// Operations on SIMD vectors of [`bf16`].
+///
+/// This trait is not included in the prelude, because its methods share names with
+/// [`SimdFloat`](crate::simd::num::SimdFloat).
+pub impl(self) trait SimdBf16: Copy {
+    /// Vector of `f32` with the same number of elements.
+    type F32;
+
+    /// Bit representation of this SIMD vector type.
+    type Bits;
+
+    /// Raw transmutation from an unsigned integer vector type with the same number of
+    /// elements.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Raw transmutation to an unsigned integer vector type with the same number of
+    /// elements.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn to_bits(self) -> Self::Bits;
+
+    /// Converts each element of an `f32` vector to the nearest `bf16`, with ties rounding to
+    /// even.
+    ///
+    /// NaN is converted to a quiet NaN.  Subnormal results may be flushed to zero on targets
+    /// with hardware conversions that do so, such as x86 AVX-512 BF16.
+    ///
+    /// x86 targets with AVX-512 BF16 use a hardware conversion for vectors of 4, 8, or 16
+    /// elements, and AArch64 targets with the `bf16` feature use `bfcvtn`/`bfcvtn2` for vectors
+    /// of 4 or 8 elements.  Other targets and lengths use a portable integer implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// # use simd::SimdBf16;
+    /// let x = f32x4::from_array([1.0, 1.00390625, 1.01171875, -3.5]);
+    /// let y = bf16x4::from_f32(x);
+    /// assert_eq!(y.to_f32(), f32x4::from_array([1.0, 1.0, 1.015625, -3.5]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn from_f32(x: Self::F32) -> Self;
+
+    /// Converts each element to an `f32`.  This conversion is exact.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn to_f32(self) -> Self::F32;
+}
+
+impl<const N: usize> SimdBf16 for Simd<bf16, N> {
+    type F32 = Simd<f32, N>;
+    type Bits = Simd<u16, N>;
+
+    #[inline]
+    fn from_bits(bits: Simd<u16, N>) -> Self {
+        // Safety: `bf16` is a transparent wrapper around `u16`
+        unsafe { mem::transmute_copy(&bits) }
+    }
+
+    #[inline]
+    fn to_bits(self) -> Simd<u16, N> {
+        // Safety: `bf16` is a transparent wrapper around `u16`
+        unsafe { mem::transmute_copy(&self) }
+    }
+
+    #[inline]
+    fn from_f32(x: Simd<f32, N>) -> Self {
+        #![allow(unused_imports, unused_unsafe)]
+        use crate::core_simd::transize::transize;
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64 as x86;
+        // SAFETY: Intrinsics covered by cfg.  The conversions produce at least `N` elements, and
+        // possibly zeroed padding after them.
+        unsafe {
+            #[allow(
+                unreachable_patterns,
+                reason = "avoids writing verbose cfg(not), earlier branches take priority"
+            )]
+            match N {
+                #[cfg(all(target_feature = "avx512bf16", target_feature = "avx512vl"))]
+                4 => transize!(x86::_mm_cvtneps_pbh, x),
+                #[cfg(all(target_feature = "avx512bf16", target_feature = "avx512vl"))]
+                8 => transize!(x86::_mm256_cvtneps_pbh, x),
+                #[cfg(target_feature = "avx512bf16")]
+                16 => transize!(x86::_mm512_cvtneps_pbh, x),
+                #[cfg(all(target_arch = "aarch64", target_feature = "bf16"))]
+                4 => transize!(aarch64::bfcvtn, x),
+                #[cfg(all(target_arch = "aarch64", target_feature = "bf16"))]
+                8 => transize!(aarch64::bfcvtn2, x),
+                _ => {
+                    use crate::simd::{
+                        Select,
+                        cmp::SimdPartialEq,
+                        num::{SimdFloat, SimdUint},
+                    };
+                    let bits = x.to_bits();
+                    let rounded =
+                        (bits + Simd::splat(0x7fff) + ((bits >> 16) & Simd::splat(1))) >> 16;
+                    let nan = (bits >> 16) | Simd::splat(0x0040);
+                    let bits = x.simd_ne(x).select(nan, rounded);
+                    <Self as SimdBf16>::from_bits(bits.cast())
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn to_f32(self) -> Simd<f32, N> {
+        use crate::simd::num::{SimdFloat, SimdUint};
+        Simd::<f32, N>::from_bits(self.to_bits().cast::<u32>() << 16)
+    }
+}
+
+impl<const N: usize> From<Simd<bf16, N>> for Simd<f32, N> {
+    #[inline]
+    fn from(value: Simd<bf16, N>) -> Self {
+        SimdBf16::to_f32(value)
+    }
+}
+
+// FIXME: use the `core::arch` intrinsics once they're available
+#[cfg(all(target_arch = "aarch64", target_feature = "bf16"))]
+mod aarch64 {
+    use crate::simd::Simd;
+    use core::arch::asm;
+
+    /// Converts 4 elements with `bfcvtn`.
+    #[inline(always)]
+    pub(super) unsafe fn bfcvtn(x: Simd<f32, 4>) -> Simd<u16, 4> {
+        let out: Simd<u16, 4>;
+        // SAFETY: the `bf16` target feature is enabled
+        unsafe {
+            asm!(
+                "bfcvtn {out:v}.4h, {x:v}.4s",
+                x = in(vreg) x,
+                out = lateout(vreg) out,
+                options(pure, nomem, nostack, preserves_flags),
+            );
+        }
+        out
+    }
+
+    /// Converts 8 elements with `bfcvtn` and `bfcvtn2`.
+    #[inline(always)]
+    pub(super) unsafe fn bfcvtn2(x: Simd<f32, 8>) -> Simd<u16, 8> {
+        let out: Simd<u16, 8>;
+        // SAFETY: the `bf16` target feature is enabled, and `out` doesn't overlap `hi`
+        unsafe {
+            asm!(
+                "bfcvtn {out:v}.4h, {lo:v}.4s",
+                "bfcvtn2 {out:v}.8h, {hi:v}.4s",
+                lo = in(vreg) x.extract::<0, 4>(),
+                hi = in(vreg) x.extract::<4, 4>(),
+                out = out(vreg) out,
+                options(pure, nomem, nostack, preserves_flags),
+            );
+        }
+        out
+    }
+}
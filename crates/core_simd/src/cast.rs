@@ -5,31 +5,45 @@ use crate::simd::SimdElement;
 /// # Safety
 /// Implementing this trait asserts that the type is a valid vector element for the `simd_cast` or
 /// `simd_as` intrinsics.
-pub impl(self) unsafe trait SimdCast: SimdElement {}
+pub impl(self) unsafe trait SimdCast: SimdElement {
+    /// Whether this is a float type.
+    #[doc(hidden)]
+    const FLOAT: bool;
 
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for i8 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for i16 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for i32 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for i64 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for isize {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for u8 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for u16 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for u32 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for u64 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for usize {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for f16 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for f32 {}
-// Safety: primitive number types can be cast to other primitive number types
-unsafe impl SimdCast for f64 {}
+    /// Whether this type can represent negative values.
+    #[doc(hidden)]
+    const SIGNED: bool;
+
+    /// The size of this type in bits.
+    #[doc(hidden)]
+    const BITS: u32;
+}
+
+macro_rules! impl_int {
+    { $($ty:ty),* } => {
+        $(
+        // Safety: primitive number types can be cast to other primitive number types
+        unsafe impl SimdCast for $ty {
+            const FLOAT: bool = false;
+            const SIGNED: bool = <$ty>::MIN != 0;
+            const BITS: u32 = <$ty>::BITS;
+        }
+        )*
+    }
+}
+
+macro_rules! impl_float {
+    { $($ty:ty),* } => {
+        $(
+        // Safety: primitive number types can be cast to other primitive number types
+        unsafe impl SimdCast for $ty {
+            const FLOAT: bool = true;
+            const SIGNED: bool = true;
+            const BITS: u32 = size_of::<$ty>() as u32 * 8;
+        }
+        )*
+    }
+}
+
+impl_int! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+impl_float! { f16, f32, f64 }
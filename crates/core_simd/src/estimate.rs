@@ -0,0 +1,258 @@
+#[allow(unused_imports, reason = "only used on some targets/features")]
+use crate::core_simd::transize::transize;
+use crate::simd::Simd;
+use core::mem;
+
+/// Reciprocal and reciprocal square root estimates, used to implement
+/// `SimdFloat::recip_estimate` and related functions.
+///
+/// During const evaluation the `f32` estimates are exact; `SimdFloat::recip_estimate` documents
+/// why.
+pub(crate) const trait Estimate: Sized {
+    /// Returns an estimate of the reciprocal of each element, along with the number of
+    /// Newton-Raphson steps needed to refine it to single precision.
+    fn recip_estimate_steps(self) -> (Self, usize);
+
+    /// Returns an estimate of the reciprocal square root of each element, along with the
+    /// number of Newton-Raphson steps needed to refine it to single precision.
+    fn rsqrt_estimate_steps(self) -> (Self, usize);
+}
+
+macro_rules! impl_exact {
+    { $($ty:ty),* } => {
+        $(
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Estimate for Simd<$ty, N> {
+            #[inline]
+            fn recip_estimate_steps(self) -> (Self, usize) {
+                (Self::splat(1.0) / self, 0)
+            }
+
+            #[inline]
+            fn rsqrt_estimate_steps(self) -> (Self, usize) {
+                (Self::splat(1.0) / Sqrt::sqrt(self), 0)
+            }
+        }
+        )*
+    }
+}
+
+impl_exact! { f16, f64 }
+
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<const N: usize> const Estimate for Simd<f32, N> {
+    #[inline]
+    fn recip_estimate_steps(self) -> (Self, usize) {
+        core::intrinsics::const_eval_select((self,), recip_exact, recip_f32)
+    }
+
+    #[inline]
+    fn rsqrt_estimate_steps(self) -> (Self, usize) {
+        core::intrinsics::const_eval_select((self,), rsqrt_exact, rsqrt_f32)
+    }
+}
+
+#[inline]
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+const fn recip_exact<const N: usize>(x: Simd<f32, N>) -> (Simd<f32, N>, usize) {
+    (Simd::splat(1.0) / x, 0)
+}
+
+#[inline]
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+const fn rsqrt_exact<const N: usize>(x: Simd<f32, N>) -> (Simd<f32, N>, usize) {
+    (Simd::splat(1.0) / Sqrt::sqrt(x), 0)
+}
+
+#[inline]
+fn recip_f32<const N: usize>(x: Simd<f32, N>) -> (Simd<f32, N>, usize) {
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    use core::arch::aarch64;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+    // SAFETY: Intrinsics covered by cfg
+    unsafe {
+        #[allow(
+            unreachable_patterns,
+            reason = "avoids writing verbose cfg(not), earlier branches take priority"
+        )]
+        match N {
+            // AArch64 FRECPE has 8 bits of precision
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            2 => (transize!(aarch64::vrecpe_f32, x), 2),
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            4 => (transize!(aarch64::vrecpeq_f32, x), 2),
+
+            // x86 RCPPS has 12 bits of precision
+            #[cfg(target_feature = "sse")]
+            4 => (transize!(x86::_mm_rcp_ps, x), 1),
+            #[cfg(target_feature = "avx")]
+            8 => (transize!(x86::_mm256_rcp_ps, x), 1),
+
+            // exact fallback
+            _ => recip_exact(x),
+        }
+    }
+}
+
+#[inline]
+fn rsqrt_f32<const N: usize>(x: Simd<f32, N>) -> (Simd<f32, N>, usize) {
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    use core::arch::aarch64;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+    // SAFETY: Intrinsics covered by cfg
+    unsafe {
+        #[allow(
+            unreachable_patterns,
+            reason = "avoids writing verbose cfg(not), earlier branches take priority"
+        )]
+        match N {
+            // AArch64 FRSQRTE has 8 bits of precision
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            2 => (transize!(aarch64::vrsqrte_f32, x), 2),
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            4 => (transize!(aarch64::vrsqrteq_f32, x), 2),
+
+            // x86 RSQRTPS has 12 bits of precision
+            #[cfg(target_feature = "sse")]
+            4 => (transize!(x86::_mm_rsqrt_ps, x), 1),
+            #[cfg(target_feature = "avx")]
+            8 => (transize!(x86::_mm256_rsqrt_ps, x), 1),
+
+            // exact fallback
+            _ => rsqrt_exact(x),
+        }
+    }
+}
+
+/// Correctly rounded square roots, used to implement `SimdFloat::sqrt`.
+///
+/// Target intrinsics can't be evaluated in const contexts, so const evaluation computes each
+/// element in software instead.
+pub(crate) const trait Sqrt {
+    /// Returns the square root of each element.
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_sqrt {
+    { $($ty:ident),* } => {
+        $(
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Sqrt for Simd<$ty, N> {
+            #[inline]
+            fn sqrt(self) -> Self {
+                #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+                const fn compiletime<const N: usize>(x: Simd<$ty, N>) -> Simd<$ty, N> {
+                    const MANTISSA_BITS: u32 = <$ty>::MANTISSA_DIGITS - 1;
+                    const EXP_BITS: u32 = mem::size_of::<$ty>() as u32 * 8 - MANTISSA_BITS - 1;
+                    let mut x = x.to_array();
+                    let mut i = 0;
+                    while i < N {
+                        let bits = sqrt_bits(x[i].to_bits() as u64, MANTISSA_BITS, EXP_BITS);
+                        x[i] = <$ty>::from_bits(bits as _);
+                        i += 1;
+                    }
+                    Simd::from_array(x)
+                }
+
+                #[inline]
+                fn runtime<const N: usize>(x: Simd<$ty, N>) -> Simd<$ty, N> {
+                    // Safety: `x` is a float vector
+                    unsafe { core::intrinsics::simd::simd_fsqrt(x) }
+                }
+
+                core::intrinsics::const_eval_select((self,), compiletime, runtime)
+            }
+        }
+        )*
+    }
+}
+
+impl_sqrt! { f16, f32, f64 }
+
+/// Computes a correctly rounded square root from the bits of an IEEE 754 binary float with the
+/// given field widths.
+///
+/// The significand is scaled so that its integer square root has one bit beyond the result's
+/// precision, which is then rounded to nearest, ties to even, using whether the integer square
+/// root was exact.
+const fn sqrt_bits(bits: u64, mantissa_bits: u32, exp_bits: u32) -> u64 {
+    let exp_max = (1 << exp_bits) - 1;
+    let bias = exp_max as i32 >> 1;
+    let mantissa_mask = (1 << mantissa_bits) - 1;
+    let quiet_nan = (exp_max << mantissa_bits) | (1 << (mantissa_bits - 1));
+
+    let sign = bits >> (mantissa_bits + exp_bits);
+    let exp = (bits >> mantissa_bits) & exp_max;
+    let fraction = bits & mantissa_mask;
+    if exp == exp_max && fraction != 0 {
+        // Quiet the NaN, keeping its payload
+        return bits | quiet_nan;
+    }
+    if exp == 0 && fraction == 0 {
+        // Zeros keep their sign
+        return bits;
+    }
+    if sign != 0 {
+        return quiet_nan;
+    }
+    if exp == exp_max {
+        return bits;
+    }
+
+    // Normalize the significand, so the value is `m * 2^(e - mantissa_bits)`
+    let (mut m, mut e) = if exp == 0 {
+        (fraction, 1 - bias)
+    } else {
+        (fraction | (1 << mantissa_bits), exp as i32 - bias)
+    };
+    while m & (1 << mantissa_bits) == 0 {
+        m <<= 1;
+        e -= 1;
+    }
+
+    // Scale to `m * 2^f` with an even `f`, where the square root of `m` has `mantissa_bits + 2`
+    // bits
+    let shift = mantissa_bits + 2;
+    let mut m = (m as u128) << shift;
+    let mut f = e - mantissa_bits as i32 - shift as i32;
+    if f % 2 != 0 {
+        m <<= 1;
+        f -= 1;
+    }
+    let root = m.isqrt();
+    let inexact = root * root != m;
+
+    // Drop the extra bit, rounding to nearest even
+    let mut significand = (root >> 1) as u64;
+    if root & 1 != 0 && (inexact || significand & 1 != 0) {
+        significand += 1;
+    }
+    let mut e = f / 2 + 1 + mantissa_bits as i32;
+    if significand >> (mantissa_bits + 1) != 0 {
+        significand >>= 1;
+        e += 1;
+    }
+
+    // Square roots of finite values are always normal
+    (((e + bias) as u64) << mantissa_bits) | (significand & mantissa_mask)
+}
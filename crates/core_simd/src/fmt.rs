@@ -18,3 +18,103 @@ where
         <[T] as fmt::Debug>::fmt(self.as_array(), f)
     }
 }
+
+macro_rules! impl_fmt_trait {
+    { $($(#[$attr:meta])* $trait:ident,)* } => {
+        $(
+            impl<T, const N: usize> fmt::$trait for Simd<T, N>
+            where
+                T: SimdElement + fmt::$trait,
+            {
+                $(#[$attr])*
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str("[")?;
+                    for (i, x) in self.as_array().iter().enumerate() {
+                        if i != 0 {
+                            f.write_str(", ")?;
+                        }
+                        fmt::$trait::fmt(x, f)?;
+                    }
+                    f.write_str("]")
+                }
+            }
+        )*
+    }
+}
+
+impl_fmt_trait! {
+    /// Formats each element with the same formatter options, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1.0f32, 2.5, -0.125]);
+    /// assert_eq!(format!("{x}"), "[1, 2.5, -0.125]");
+    /// assert_eq!(format!("{x:.2}"), "[1.00, 2.50, -0.12]");
+    /// ```
+    Display,
+    /// Formats each element in binary, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1u8, 10, 255]);
+    /// assert_eq!(format!("{x:08b}"), "[00000001, 00001010, 11111111]");
+    /// ```
+    Binary,
+    /// Formats each element in octal, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1u8, 10, 255]);
+    /// assert_eq!(format!("{x:#o}"), "[0o1, 0o12, 0o377]");
+    /// ```
+    Octal,
+    /// Formats each element in lowercase hexadecimal, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1u8, 10, 255]);
+    /// assert_eq!(format!("{x:#04x}"), "[0x01, 0x0a, 0xff]");
+    /// ```
+    LowerHex,
+    /// Formats each element in uppercase hexadecimal, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1u8, 10, 255]);
+    /// assert_eq!(format!("{x:X}"), "[1, A, FF]");
+    /// ```
+    UpperHex,
+    /// Formats each element in lowercase scientific notation, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1500.0f64, 0.25]);
+    /// assert_eq!(format!("{x:e}"), "[1.5e3, 2.5e-1]");
+    /// ```
+    LowerExp,
+    /// Formats each element in uppercase scientific notation, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([1500.0f64, 0.25]);
+    /// assert_eq!(format!("{x:.1E}"), "[1.5E3, 2.5E-1]");
+    /// ```
+    UpperExp,
+    /// Formats each element as a pointer, like a list:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Simd;
+    /// let x = Simd::from_array([core::ptr::null::<u8>(); 2]);
+    /// assert_eq!(format!("{x:p}"), "[0x0, 0x0]");
+    /// ```
+    Pointer,
+}
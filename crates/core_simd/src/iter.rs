@@ -1,7 +1,10 @@
-use crate::simd::Simd;
+use crate::simd::{Mask, MaskElement, Simd, SimdElement};
 use core::{
+    array,
+    borrow::Borrow,
     iter::{Product, Sum},
-    ops::{Add, Mul},
+    ops::{Add, BitAnd, BitOr, Mul},
+    slice,
 };
 
 macro_rules! impl_traits {
@@ -40,15 +43,148 @@ macro_rules! impl_traits {
     }
 }
 
+impl_traits! { f16 }
 impl_traits! { f32 }
 impl_traits! { f64 }
 impl_traits! { u8 }
 impl_traits! { u16 }
 impl_traits! { u32 }
 impl_traits! { u64 }
+impl_traits! { u128 }
 impl_traits! { usize }
 impl_traits! { i8 }
 impl_traits! { i16 }
 impl_traits! { i32 }
 impl_traits! { i64 }
+impl_traits! { i128 }
 impl_traits! { isize }
+
+impl<T, const N: usize> Mask<T, N>
+where
+    T: MaskElement,
+{
+    /// Combines masks with a bitwise OR, setting each element that is set in any mask.
+    ///
+    /// Returns a mask with every element unset if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let data = [1, -2, 3, 4, -5, 6, 7, 8];
+    /// let chunks = data.chunks_exact(4).map(|chunk| i32x4::from_slice(chunk).simd_lt(i32x4::splat(0)));
+    /// let negative = Mask::any_of(chunks);
+    /// assert_eq!(negative.to_array(), [true, true, false, false]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn any_of<I>(masks: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        masks
+            .into_iter()
+            .fold(Self::splat(false), |acc, mask| acc.bitor(*mask.borrow()))
+    }
+
+    /// Combines masks with a bitwise AND, setting each element that is set in every mask.
+    ///
+    /// Returns a mask with every element set if the iterator is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let masks = [
+    ///     mask32x4::from_array([true, true, false, true]),
+    ///     mask32x4::from_array([true, false, false, true]),
+    /// ];
+    /// assert_eq!(Mask::all_of(&masks).to_array(), [true, false, false, true]);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn all_of<I>(masks: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self>,
+    {
+        masks
+            .into_iter()
+            .fold(Self::splat(true), |acc, mask| acc.bitand(*mask.borrow()))
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Simd<T, N>
+where
+    T: SimdElement,
+{
+    type Item = T;
+    type IntoIter = array::IntoIter<T, N>;
+
+    /// Creates an iterator over the elements of the vector, like an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{mask32x4, u32x4};
+    /// let v = u32x4::from_array([3, 1, 4, 1]);
+    /// assert_eq!(v.into_iter().max(), Some(4));
+    /// assert_eq!((&v).into_iter().rev().collect::<Vec<_>>(), [&1, &4, &1, &3]);
+    ///
+    /// let mask = mask32x4::from_array([true, false, true, true]);
+    /// assert_eq!(mask.into_iter().filter(|&x| x).count(), 3);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Simd<T, N>
+where
+    T: SimdElement,
+{
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_array().iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Simd<T, N>
+where
+    T: SimdElement,
+{
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_array().iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Mask<T, N>
+where
+    T: MaskElement,
+{
+    type Item = bool;
+    type IntoIter = array::IntoIter<bool, N>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.to_array().into_iter()
+    }
+}
@@ -1,5 +1,10 @@
 #![no_std]
 #![feature(
+    allow_internal_unstable,
+    associated_type_defaults,
+    const_eval_select,
+    const_ops,
+    const_trait_impl,
     convert_float_to_int,
     f16,
     core_intrinsics,
@@ -2,7 +2,7 @@
 //! Types representing
 #![allow(non_camel_case_types)]
 
-use crate::simd::{Select, Simd, SimdCast, SimdElement};
+use crate::simd::{Simd, SimdCast, SimdElement};
 use core::cmp::Ordering;
 use core::{fmt, mem};
 
@@ -39,10 +39,6 @@ mod private_methods {
     /// prevent us from ever removing that bound, or from implementing `MaskElement` on
     /// non-`PartialEq` types in the future.
     pub impl(super) trait PrivateMethods {
-        fn valid<const N: usize>(values: Simd<Self, N>) -> bool
-        where
-            Self: SimdElement;
-
         fn eq(self, other: Self) -> bool;
 
         fn to_usize(self) -> usize;
@@ -69,21 +65,6 @@ pub impl(self) unsafe trait MaskElement:
 macro_rules! impl_element {
     { $ty:ty, $unsigned:ty } => {
         impl PrivateMethods for $ty {
-            #[inline]
-            fn valid<const N: usize>(value: Simd<Self, N>) -> bool
-            {
-                // We can't use `Simd` directly, because `Simd`'s functions call this function and
-                // we will end up with an infinite loop.
-                // Safety: `value` is an integer vector
-                unsafe {
-                    use core::intrinsics::simd;
-                    let falses: Simd<Self, N> = simd::simd_eq(value, Simd::splat(0 as _));
-                    let trues: Simd<Self, N> = simd::simd_eq(value, Simd::splat(-1 as _));
-                    let valid: Simd<Self, N> = simd::simd_or(falses, trues);
-                    simd::simd_reduce_all(valid)
-                }
-            }
-
             #[inline]
             fn eq(self, other: Self) -> bool { self == other }
 
@@ -112,6 +93,7 @@ impl_element! { i8, u8 }
 impl_element! { i16, u16 }
 impl_element! { i32, u32 }
 impl_element! { i64, u64 }
+impl_element! { i128, u128 }
 impl_element! { isize, usize }
 
 /// A SIMD vector mask for `N` elements of width specified by `Element`.
@@ -122,7 +104,7 @@ impl_element! { isize, usize }
 /// and/or Rust versions, and code should not assume that it is equivalent to
 /// `[T; N]`.
 ///
-/// `N` cannot be 0 and may be at most 64. This limit may be increased in
+/// `N` cannot be 0 and may be at most 512. This limit may be increased in
 /// the future.
 #[repr(transparent)]
 pub struct Mask<T, const N: usize>(Simd<T, N>)
@@ -154,7 +136,8 @@ where
 
     /// Converts an array of bools to a SIMD mask.
     #[inline]
-    pub fn from_array(array: [bool; N]) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn from_array(array: [bool; N]) -> Self {
         // SAFETY: Rust's bool has a layout of 1 byte (u8) with a value of
         //     true:    0b_0000_0001
         //     false:   0b_0000_0000
@@ -169,9 +152,31 @@ where
         }
     }
 
+    /// Constructs a mask by calling a function with the index of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::mask32x4;
+    /// let mask = mask32x4::from_fn(|i| i % 2 == 0);
+    /// assert_eq!(mask.to_array(), [true, false, true, false]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> bool,
+    {
+        Self::from_array(core::array::from_fn(f))
+    }
+
     /// Converts a SIMD mask to an array of bools.
     #[inline]
-    pub fn to_array(self) -> [bool; N] {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn to_array(self) -> [bool; N] {
         // This follows mostly the same logic as from_array.
         // SAFETY: Rust's bool has a layout of 1 byte (u8) with a value of
         //     true:    0b_0000_0001
@@ -189,6 +194,21 @@ where
         }
     }
 
+    /// Returns true if every element is 0 or -1.
+    #[inline]
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub(crate) const fn valid(value: Simd<T, N>) -> bool {
+        // We can't use `Simd`'s comparisons, because they construct masks with this function.
+        // Safety: `value` is an integer vector
+        unsafe {
+            use core::intrinsics::simd;
+            let falses: Simd<T, N> = simd::simd_eq(value, Simd::splat(T::FALSE));
+            let trues: Simd<T, N> = simd::simd_eq(value, Simd::splat(T::TRUE));
+            let valid: Simd<T, N> = simd::simd_or(falses, trues);
+            simd::simd_reduce_all(valid)
+        }
+    }
+
     /// Converts a vector of integers to a mask, where 0 represents `false` and -1
     /// represents `true`.
     ///
@@ -196,10 +216,11 @@ where
     /// All elements must be either 0 or -1.
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
-    pub unsafe fn from_simd_unchecked(value: Simd<T, N>) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const unsafe fn from_simd_unchecked(value: Simd<T, N>) -> Self {
         // Safety: the caller must confirm this invariant
         unsafe {
-            core::intrinsics::assume(<T as PrivateMethods>::valid(value));
+            core::intrinsics::assume(Self::valid(value));
         }
         Self(value)
     }
@@ -212,8 +233,9 @@ where
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
     #[track_caller]
-    pub fn from_simd(value: Simd<T, N>) -> Self {
-        assert!(T::valid(value), "all values must be either 0 or -1",);
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn from_simd(value: Simd<T, N>) -> Self {
+        assert!(Self::valid(value), "all values must be either 0 or -1",);
         // Safety: the validity has been checked
         unsafe { Self::from_simd_unchecked(value) }
     }
@@ -222,14 +244,16 @@ where
     /// represents `true`.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original value"]
-    pub fn to_simd(self) -> Simd<T, N> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn to_simd(self) -> Simd<T, N> {
         self.0
     }
 
     /// Converts the mask to a mask of any other element size.
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
-    pub fn cast<U: MaskElement>(self) -> Mask<U, N> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn cast<U: MaskElement>(self) -> Mask<U, N> {
         // Safety: mask elements are integers
         unsafe { Mask(core::intrinsics::simd::simd_as(self.0)) }
     }
@@ -281,7 +305,8 @@ where
     /// Returns true if any element is set, or false otherwise.
     #[inline]
     #[must_use = "method returns a new bool and does not mutate the original value"]
-    pub fn any(self) -> bool {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn any(self) -> bool {
         // Safety: `self` is a mask vector
         unsafe { core::intrinsics::simd::simd_reduce_any(self.0) }
     }
@@ -289,7 +314,8 @@ where
     /// Returns true if all elements are set, or false otherwise.
     #[inline]
     #[must_use = "method returns a new bool and does not mutate the original value"]
-    pub fn all(self) -> bool {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn all(self) -> bool {
         // Safety: `self` is a mask vector
         unsafe { core::intrinsics::simd::simd_reduce_all(self.0) }
     }
@@ -297,6 +323,9 @@ where
     /// Creates a bitmask from a mask.
     ///
     /// Each bit is set if the corresponding element in the mask is `true`.
+    ///
+    /// The mask may contain at most 64 elements.  For larger masks, use
+    /// [`to_bitmask_array`](Self::to_bitmask_array).
     #[inline]
     #[must_use = "method returns a new integer and does not mutate the original value"]
     pub fn to_bitmask(self) -> u64 {
@@ -304,6 +333,12 @@ where
             assert!(N <= 64, "number of elements can't be greater than 64");
         }
 
+        self.to_bitmask_truncated()
+    }
+
+    /// Creates a bitmask from the first 64 elements of a mask.
+    #[inline]
+    fn to_bitmask_truncated(self) -> u64 {
         #[inline]
         unsafe fn to_bitmask_impl<T, U: FixEndianness, const M: usize, const N: usize>(
             mask: Mask<T, N>,
@@ -339,11 +374,147 @@ where
     /// Creates a mask from a bitmask.
     ///
     /// For each bit, if it is set, the corresponding element in the mask is set to `true`.
-    /// If the mask contains more than 64 elements, the remainder are set to `false`.
+    ///
+    /// The mask may contain at most 64 elements.  For larger masks, use
+    /// [`from_bitmask_array`](Self::from_bitmask_array).
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original value"]
     pub fn from_bitmask(bitmask: u64) -> Self {
-        Self(bitmask.select(Simd::splat(T::TRUE), Simd::splat(T::FALSE)))
+        const {
+            assert!(
+                N <= 64,
+                "number of elements can't be greater than 64, use `Mask::from_bitmask_array` instead"
+            );
+        }
+
+        Self::from_bitmask_truncated(bitmask)
+    }
+
+    /// Creates a mask with at most 64 elements from a bitmask.
+    #[inline]
+    fn from_bitmask_truncated(bitmask: u64) -> Self {
+        Self(crate::core_simd::select::select_bitmask(
+            bitmask,
+            Simd::splat(T::TRUE),
+            Simd::splat(T::FALSE),
+        ))
+    }
+
+    /// Creates an array of bitmasks from a mask, with 64 elements per word.
+    ///
+    /// Bit `i % 64` of word `i / 64` is set if element `i` of the mask is `true`.  The array
+    /// must have at least enough words to hold every element, and any extra bits are unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Mask;
+    /// let mask = Mask::<i8, 128>::from_array(core::array::from_fn(|i| i % 3 == 0 || i == 127));
+    /// let words = mask.to_bitmask_array::<2>();
+    /// assert_eq!(words, [0x9249249249249249, 0xc924924924924924]);
+    /// assert_eq!(Mask::from_bitmask_array(words), mask);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new array and does not mutate the original value"]
+    pub fn to_bitmask_array<const W: usize>(self) -> [u64; W] {
+        const {
+            assert!(W * 64 >= N, "the array is too small to hold the bitmask");
+        }
+
+        #[inline]
+        unsafe fn to_bitmask_array_impl<
+            T,
+            const M: usize,
+            const B: usize,
+            const N: usize,
+            const W: usize,
+        >(
+            mask: Mask<T, N>,
+        ) -> [u64; W]
+        where
+            T: MaskElement,
+        {
+            let resized = mask.resize::<M>(false);
+
+            // Safety: `resized` is an integer vector with length M, which must match B bytes
+            let bytes: [u8; B] = unsafe { core::intrinsics::simd::simd_bitmask(resized.0) };
+
+            let mut words = [0; W];
+            for (i, byte) in bytes.into_iter().enumerate().take(W * 8) {
+                // LLVM assumes bit order should match endianness
+                words[i / 8] |= (byte.fix_endianness() as u64) << (i % 8 * 8);
+            }
+            words
+        }
+
+        if N <= 64 {
+            let mut words = [0; W];
+            words[0] = self.to_bitmask_truncated();
+            words
+        } else if N <= 128 {
+            // Safety: bitmask matches length
+            unsafe { to_bitmask_array_impl::<T, 128, 16, N, W>(self) }
+        } else if N <= 256 {
+            // Safety: bitmask matches length
+            unsafe { to_bitmask_array_impl::<T, 256, 32, N, W>(self) }
+        } else {
+            // Safety: bitmask matches length
+            unsafe { to_bitmask_array_impl::<T, 512, 64, N, W>(self) }
+        }
+    }
+
+    /// Creates a mask from an array of bitmasks, with 64 elements per word.
+    ///
+    /// Element `i` of the mask is set to `true` if bit `i % 64` of word `i / 64` is set.  Any
+    /// extra bits are ignored, and any elements beyond the end of the array are set to `false`.
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn from_bitmask_array<const W: usize>(bitmask: [u64; W]) -> Self {
+        #[inline]
+        unsafe fn from_bitmask_array_impl<
+            T,
+            const M: usize,
+            const B: usize,
+            const N: usize,
+            const W: usize,
+        >(
+            bitmask: [u64; W],
+        ) -> Mask<T, N>
+        where
+            T: MaskElement,
+        {
+            let mut bytes = [0u8; B];
+            for (i, byte) in bytes.iter_mut().enumerate().take(W * 8) {
+                // LLVM assumes bit order should match endianness
+                *byte = ((bitmask[i / 8] >> (i % 8 * 8)) as u8).fix_endianness();
+            }
+
+            // Safety: the size of the bitmask matches M
+            let selected: Simd<T, M> = unsafe {
+                core::intrinsics::simd::simd_select_bitmask(
+                    bytes,
+                    Simd::splat(T::TRUE),
+                    Simd::splat(T::FALSE),
+                )
+            };
+            Mask(selected).resize::<N>(false)
+        }
+
+        if N <= 64 {
+            Self::from_bitmask_truncated(bitmask.first().copied().unwrap_or(0))
+        } else if N <= 128 {
+            // Safety: bitmask matches length
+            unsafe { from_bitmask_array_impl::<T, 128, 16, N, W>(bitmask) }
+        } else if N <= 256 {
+            // Safety: bitmask matches length
+            unsafe { from_bitmask_array_impl::<T, 256, 32, N, W>(bitmask) }
+        } else {
+            // Safety: bitmask matches length
+            unsafe { from_bitmask_array_impl::<T, 512, 64, N, W>(bitmask) }
+        }
     }
 
     /// Finds the index of the first set element.
@@ -364,7 +535,13 @@ where
     pub fn first_set(self) -> Option<usize> {
         // If bitmasks are efficient, using them is better
         if cfg!(target_feature = "sse") && N <= 64 {
-            return self.to_bitmask().lowest_one().map(|i| i as usize);
+            return self.to_bitmask_truncated().lowest_one().map(|i| i as usize);
+        }
+
+        // Large masks may have more elements than can be indexed by `T`
+        if N > 64 && (cfg!(target_feature = "sse") || N as u64 > T::max_unsigned() / 2) {
+            let words = self.to_bitmask_array::<8>();
+            return (0..8).find_map(|i| words[i].lowest_one().map(|bit| i * 64 + bit as usize));
         }
 
         // To find the first set index:
@@ -432,7 +609,18 @@ where
     pub fn last_set(self) -> Option<usize> {
         // If bitmasks are efficient, using them is better
         if cfg!(target_feature = "sse") && N <= 64 {
-            return self.to_bitmask().highest_one().map(|i| i as usize);
+            return self
+                .to_bitmask_truncated()
+                .highest_one()
+                .map(|i| i as usize);
+        }
+
+        // Large masks may have more elements than can be indexed by `T`
+        if N > 64 && (cfg!(target_feature = "sse") || N as u64 > T::max_unsigned() / 2) {
+            let words = self.to_bitmask_array::<8>();
+            return (0..8)
+                .rev()
+                .find_map(|i| words[i].highest_one().map(|bit| i * 64 + bit as usize));
         }
 
         // To find the first set index:
@@ -538,7 +726,82 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitAnd for Mask<T, N>
+impl<T, const N: usize> fmt::Display for Mask<T, N>
+where
+    T: MaskElement,
+{
+    /// Formats the mask as a string of `0` and `1`, starting with the first element.
+    ///
+    /// The width, fill and alignment options apply to the whole string.
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Mask;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Mask;
+    /// let mask = Mask::<i32, 4>::from_array([true, false, true, true]);
+    /// assert_eq!(format!("{mask}"), "1011");
+    /// assert_eq!(format!("{mask:>6}"), "  1011");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        let padding = f.width().unwrap_or(0).saturating_sub(N);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        for i in 0..N {
+            f.write_char(if self.test(i) { '1' } else { '0' })?;
+        }
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_fmt_trait {
+    { $($(#[$attr:meta])* $trait:ident,)* } => {
+        $(
+            impl<T, const N: usize> fmt::$trait for Mask<T, N>
+            where
+                T: MaskElement + fmt::$trait,
+            {
+                $(#[$attr])*
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    }
+}
+
+impl_fmt_trait! {
+    Binary,
+    Octal,
+    /// Formats each element as an integer, `0` for `false` and `-1` for `true`, like [`Simd`].
+    /// The other integer formatting traits work the same way:
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd::Mask;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd::Mask;
+    /// let mask = Mask::<i8, 2>::from_array([true, false]);
+    /// assert_eq!(format!("{mask:x}"), "[ff, 0]");
+    /// assert_eq!(format!("{mask:#04X}"), "[0xFF, 0x00]");
+    /// assert_eq!(format!("{mask:#b}"), "[0b11111111, 0b0]");
+    /// assert_eq!(format!("{mask:o}"), "[377, 0]");
+    /// ```
+    LowerHex,
+    UpperHex,
+}
+
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitAnd for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -550,7 +813,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitAnd<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitAnd<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -561,7 +825,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitAnd<Mask<T, N>> for bool
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitAnd<Mask<T, N>> for bool
 where
     T: MaskElement,
 {
@@ -572,7 +837,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitOr for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitOr for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -584,7 +850,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitOr<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitOr<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -595,7 +862,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitOr<Mask<T, N>> for bool
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitOr<Mask<T, N>> for bool
 where
     T: MaskElement,
 {
@@ -606,7 +874,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitXor for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitXor for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -618,7 +887,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitXor<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitXor<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -629,7 +899,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitXor<Mask<T, N>> for bool
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitXor<Mask<T, N>> for bool
 where
     T: MaskElement,
 {
@@ -640,7 +911,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::Not for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::Not for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -651,7 +923,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitAndAssign for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitAndAssign for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -661,7 +934,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitAndAssign<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitAndAssign<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -671,7 +945,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitOrAssign for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitOrAssign for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -681,7 +956,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitOrAssign<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitOrAssign<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -691,7 +967,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitXorAssign for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitXorAssign for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -701,7 +978,8 @@ where
     }
 }
 
-impl<T, const N: usize> core::ops::BitXorAssign<bool> for Mask<T, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, const N: usize> const core::ops::BitXorAssign<bool> for Mask<T, N>
 where
     T: MaskElement,
 {
@@ -724,8 +1002,9 @@ macro_rules! impl_from {
         )*
     }
 }
-impl_from! { i8 => i16, i32, i64, isize }
-impl_from! { i16 => i32, i64, isize, i8 }
-impl_from! { i32 => i64, isize, i8, i16 }
-impl_from! { i64 => isize, i8, i16, i32 }
-impl_from! { isize => i8, i16, i32, i64 }
+impl_from! { i8 => i16, i32, i64, i128, isize }
+impl_from! { i16 => i32, i64, i128, isize, i8 }
+impl_from! { i32 => i64, i128, isize, i8, i16 }
+impl_from! { i64 => i128, isize, i8, i16, i32 }
+impl_from! { i128 => isize, i8, i16, i32, i64 }
+impl_from! { isize => i8, i16, i32, i64, i128 }
@@ -2,14 +2,19 @@
 mod swizzle;
 
 mod alias;
+mod bf16;
 mod cast;
+mod estimate;
 mod fmt;
 mod iter;
 mod masks;
 mod ops;
+mod parse;
 mod select;
+mod simd_vector;
 mod swizzle_dyn;
 mod to_bytes;
+mod transize;
 mod vector;
 mod vendor;
 
@@ -24,10 +29,15 @@ pub mod simd {
 
     pub mod cmp;
 
+    pub mod native;
+
     pub use crate::core_simd::alias::*;
+    pub use crate::core_simd::bf16::{SimdBf16, bf16};
     pub use crate::core_simd::cast::*;
     pub use crate::core_simd::masks::*;
+    pub use crate::core_simd::parse::ParseSimdError;
     pub use crate::core_simd::select::*;
+    pub use crate::core_simd::simd_vector::*;
     pub use crate::core_simd::swizzle::*;
     pub use crate::core_simd::to_bytes::ToBytes;
     pub use crate::core_simd::vector::*;
@@ -100,8 +100,10 @@ macro_rules! int_divrem_guard {
             #[cfg(target_arch = "aarch64")]
             {
                 let mut out = Simd::splat(0 as _);
-                for i in 0..Self::LEN {
-                    out[i] = $lhs[i] $op rhs[i];
+                let mut i = 0;
+                while i < Self::LEN {
+                    out.as_mut_array()[i] = $lhs.as_array()[i] $op rhs.as_array()[i];
+                    i += 1;
                 }
                 out
             }
@@ -125,7 +127,8 @@ macro_rules! for_base_types {
             $macro_impl:ident $inner:tt
         }) => {
             $(
-                impl<const N: usize> $op<Self> for Simd<$scalar, N>
+                #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+                impl<const N: usize> const $op<Self> for Simd<$scalar, N>
                 where
                     $scalar: SimdElement,
                 {
@@ -184,7 +187,7 @@ macro_rules! for_base_ops {
 // Integers can always accept add, mul, sub, bitand, bitor, and bitxor.
 // For all of these operations, simd_* intrinsics apply wrapping logic.
 for_base_ops! {
-    T = (i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+    T = (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
     type Lhs = Simd<T, N>;
     type Rhs = Simd<T, N>;
     type Output = Self;
@@ -17,9 +17,11 @@ macro_rules! assign_ops {
                 $call:ident
             }
         })*) => {
-        $(impl<T, U, const N: usize> $assignTrait<U> for Simd<T, N>
+        $(
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<T, U, const N: usize> const $assignTrait<U> for Simd<T, N>
         where
-            Self: $trait<U, Output = Self>,
+            Self: [const] $trait<U, Output = Self>,
             T: SimdElement,
         {
             #[inline]
@@ -9,10 +9,11 @@ macro_rules! deref_lhs {
     (impl<T, const N: usize> $trait:ident for $simd:ty {
             fn $call:ident
         }) => {
-        impl<T, const N: usize> $trait<$simd> for &$simd
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<T, const N: usize> const $trait<$simd> for &$simd
         where
             T: SimdElement,
-            $simd: $trait<$simd, Output = $simd>,
+            $simd: [const] $trait<$simd, Output = $simd>,
         {
             type Output = Simd<T, N>;
 
@@ -28,10 +29,11 @@ macro_rules! deref_rhs {
     (impl<T, const N: usize> $trait:ident for $simd:ty {
             fn $call:ident
         }) => {
-        impl<T, const N: usize> $trait<&$simd> for $simd
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<T, const N: usize> const $trait<&$simd> for $simd
         where
             T: SimdElement,
-            $simd: $trait<$simd, Output = $simd>,
+            $simd: [const] $trait<$simd, Output = $simd>,
         {
             type Output = Simd<T, N>;
 
@@ -58,10 +60,11 @@ macro_rules! deref_ops {
                     fn $call
                 }
             }
-            impl<'lhs, 'rhs, T, const N: usize> $trait<&'rhs $simd> for &'lhs $simd
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            impl<'lhs, 'rhs, T, const N: usize> const $trait<&'rhs $simd> for &'lhs $simd
             where
                 T: SimdElement,
-                $simd: $trait<$simd, Output = $simd>,
+                $simd: [const] $trait<$simd, Output = $simd>,
             {
                 type Output = $simd;
 
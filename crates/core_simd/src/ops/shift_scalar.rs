@@ -5,7 +5,8 @@ use crate::simd::Simd;
 
 macro_rules! impl_splatted_shifts {
     { impl $trait:ident :: $trait_fn:ident for $ty:ty } => {
-        impl<const N: usize> core::ops::$trait<$ty> for Simd<$ty, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const core::ops::$trait<$ty> for Simd<$ty, N>
         {
             type Output = Self;
             #[inline]
@@ -14,7 +15,8 @@ macro_rules! impl_splatted_shifts {
             }
         }
 
-        impl<const N: usize> core::ops::$trait<&$ty> for Simd<$ty, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const core::ops::$trait<&$ty> for Simd<$ty, N>
         {
             type Output = Self;
             #[inline]
@@ -23,7 +25,8 @@ macro_rules! impl_splatted_shifts {
             }
         }
 
-        impl<'lhs, const N: usize> core::ops::$trait<$ty> for &'lhs Simd<$ty, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<'lhs, const N: usize> const core::ops::$trait<$ty> for &'lhs Simd<$ty, N>
         {
             type Output = Simd<$ty, N>;
             #[inline]
@@ -32,7 +35,8 @@ macro_rules! impl_splatted_shifts {
             }
         }
 
-        impl<'lhs, const N: usize> core::ops::$trait<&$ty> for &'lhs Simd<$ty, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<'lhs, const N: usize> const core::ops::$trait<&$ty> for &'lhs Simd<$ty, N>
         {
             type Output = Simd<$ty, N>;
             #[inline]
@@ -51,4 +55,4 @@ macro_rules! impl_splatted_shifts {
 
 // In the past there were inference issues when generically splatting arguments.
 // Enumerate them instead.
-impl_splatted_shifts! { i8, i16, i32, i64, isize, u8, u16, u32, u64, usize }
+impl_splatted_shifts! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
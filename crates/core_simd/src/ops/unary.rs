@@ -3,7 +3,9 @@ use core::ops::{Neg, Not}; // unary ops
 
 macro_rules! neg {
     ($(impl<const N: usize> Neg for Simd<$scalar:ty, N>)*) => {
-        $(impl<const N: usize> Neg for Simd<$scalar, N>
+        $(
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Neg for Simd<$scalar, N>
         where
             $scalar: SimdElement,
         {
@@ -33,12 +35,16 @@ neg! {
 
     impl<const N: usize> Neg for Simd<i64, N>
 
+    impl<const N: usize> Neg for Simd<i128, N>
+
     impl<const N: usize> Neg for Simd<isize, N>
 }
 
 macro_rules! not {
     ($(impl<const N: usize> Not for Simd<$scalar:ty, N>)*) => {
-        $(impl<const N: usize> Not for Simd<$scalar, N>
+        $(
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Not for Simd<$scalar, N>
         where
             $scalar: SimdElement,
         {
@@ -61,6 +67,8 @@ not! {
 
     impl<const N: usize> Not for Simd<i64, N>
 
+    impl<const N: usize> Not for Simd<i128, N>
+
     impl<const N: usize> Not for Simd<isize, N>
 
     impl<const N: usize> Not for Simd<u8, N>
@@ -71,5 +79,7 @@ not! {
 
     impl<const N: usize> Not for Simd<u64, N>
 
+    impl<const N: usize> Not for Simd<u128, N>
+
     impl<const N: usize> Not for Simd<usize, N>
 }
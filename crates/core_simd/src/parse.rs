@@ -0,0 +1,190 @@
+use crate::simd::{Mask, MaskElement, Simd, SimdElement};
+use core::{fmt, str::FromStr};
+
+/// An error which can be returned when parsing a [`Simd`] or [`Mask`] from a string.
+///
+/// If an element failed to parse, the error it returned can be retrieved with
+/// [`element_error`](Self::element_error).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseSimdError<E> {
+    kind: ErrorKind<E>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum ErrorKind<E> {
+    Syntax,
+    Count { expected: usize, found: usize },
+    Element { lane: Option<usize>, error: E },
+}
+
+impl<E> ParseSimdError<E> {
+    /// Returns the index of the element that failed to parse.
+    ///
+    /// Returns `None` if the error isn't specific to one element, such as when the string has
+    /// the wrong number of elements, or when the splatted value failed to parse.
+    #[inline]
+    pub fn lane(&self) -> Option<usize> {
+        match self.kind {
+            ErrorKind::Element { lane, .. } => lane,
+            _ => None,
+        }
+    }
+
+    /// Returns the error returned by the element's [`FromStr`] implementation, if an element
+    /// failed to parse.
+    #[inline]
+    pub fn element_error(&self) -> Option<&E> {
+        match &self.kind {
+            ErrorKind::Element { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ParseSimdError<E> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::Syntax => f.write_str("invalid vector syntax"),
+            ErrorKind::Count { expected, found } => {
+                write!(f, "expected {expected} elements, found {found}")
+            }
+            ErrorKind::Element {
+                lane: Some(lane),
+                error,
+            } => write!(f, "invalid element {lane}: {error}"),
+            ErrorKind::Element { lane: None, error } => write!(f, "invalid element: {error}"),
+        }
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for ParseSimdError<E> {
+    #[inline]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.element_error()
+            .map(|error| error as &(dyn core::error::Error + 'static))
+    }
+}
+
+/// Parses a list of elements like `[1, 2, 3, 4]`, a repeated element like `[1; 4]`, or a
+/// single element to splat.
+#[inline]
+fn parse_array<T, const N: usize>(s: &str) -> Result<[T; N], ParseSimdError<T::Err>>
+where
+    T: FromStr + Copy,
+{
+    let element = |lane, s: &str| {
+        s.trim().parse::<T>().map_err(|error| ParseSimdError {
+            kind: ErrorKind::Element { lane, error },
+        })
+    };
+    let count = |found| {
+        Err(ParseSimdError {
+            kind: ErrorKind::Count { expected: N, found },
+        })
+    };
+    let syntax = || {
+        Err(ParseSimdError {
+            kind: ErrorKind::Syntax,
+        })
+    };
+
+    let s = s.trim();
+    let Some(inner) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) else {
+        if s.starts_with('[') || s.ends_with(']') {
+            return syntax();
+        }
+        return Ok([element(None, s)?; N]);
+    };
+
+    if let Some((value, repeat)) = inner.split_once(';') {
+        let Ok(repeat) = repeat.trim().parse::<usize>() else {
+            return syntax();
+        };
+        if repeat != N {
+            return count(repeat);
+        }
+        return Ok([element(None, value)?; N]);
+    }
+
+    // Allow a trailing comma, as printed by the alternate `Debug` format
+    let inner = inner.trim();
+    let inner = inner.strip_suffix(',').unwrap_or(inner);
+    let found = if inner.trim().is_empty() {
+        0
+    } else {
+        inner.split(',').count()
+    };
+    if found != N {
+        return count(found);
+    }
+
+    let mut elements = inner.split(',').enumerate();
+    let (_, first) = elements.next().unwrap();
+    let mut array = [element(Some(0), first)?; N];
+    for (lane, s) in elements {
+        array[lane] = element(Some(lane), s)?;
+    }
+    Ok(array)
+}
+
+impl<T, const N: usize> FromStr for Simd<T, N>
+where
+    T: SimdElement + FromStr,
+{
+    type Err = ParseSimdError<T::Err>;
+
+    /// Parses a vector from its [`Debug`](fmt::Debug) format, or from a single element that is
+    /// splatted to every lane.
+    ///
+    /// A repeated element like `[1; 4]` is also accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Simd;
+    /// let x: Simd<i32, 4> = "[1, 2, 3, 4]".parse().unwrap();
+    /// assert_eq!(x, Simd::from_array([1, 2, 3, 4]));
+    ///
+    /// let y: Simd<f32, 4> = "0.5".parse().unwrap();
+    /// assert_eq!(y, Simd::splat(0.5));
+    ///
+    /// let error = "[1, 2, x, 4]".parse::<Simd<u8, 4>>().unwrap_err();
+    /// assert_eq!(error.lane(), Some(2));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_array(s).map(Self::from_array)
+    }
+}
+
+impl<T, const N: usize> FromStr for Mask<T, N>
+where
+    T: MaskElement,
+{
+    type Err = ParseSimdError<core::str::ParseBoolError>;
+
+    /// Parses a mask from its [`Debug`](fmt::Debug) format, or from a single `bool` that is
+    /// splatted to every lane.
+    ///
+    /// A repeated element like `[true; 4]` is also accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::Mask;
+    /// let mask: Mask<i32, 4> = "[true, false, false, true]".parse().unwrap();
+    /// assert_eq!(mask.to_array(), [true, false, false, true]);
+    /// assert_eq!("false".parse(), Ok(Mask::<i32, 4>::splat(false)));
+    /// ```
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_array(s).map(Self::from_array)
+    }
+}
@@ -7,6 +7,8 @@ use crate::simd::{FixEndianness, Mask, MaskElement, Simd, SimdElement};
 ///
 /// If the mask is `u64`, it's treated as a bitmask with the least significant bit
 /// corresponding to the first element.
+/// A `u64` bitmask can only select vectors with at most 64 elements; wider vectors can
+/// use a mask created by [`Mask::from_bitmask_array`].
 ///
 /// # Examples
 ///
@@ -48,12 +50,14 @@ use crate::simd::{FixEndianness, Mask, MaskElement, Simd, SimdElement};
 /// let c = mask.select(a, b);
 /// assert_eq!(c.to_array(), [true, false, true, false]);
 /// ```
-pub trait Select<T> {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const trait Select<T> {
     /// Choose elements
     fn select(self, true_values: T, false_values: T) -> T;
 }
 
-impl<T, U, const N: usize> Select<Simd<T, N>> for Mask<U, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, U, const N: usize> const Select<Simd<T, N>> for Mask<U, N>
 where
     T: SimdElement,
     U: MaskElement,
@@ -65,7 +69,11 @@ where
         // simd_select uses a mask that matches the width and number of elements
         unsafe {
             let mask: Simd<T::Mask, N> = core::intrinsics::simd::simd_as(self.to_simd());
-            core::intrinsics::simd::simd_select(mask, true_values, false_values)
+            Simd::from_repr(core::intrinsics::simd::simd_select(
+                mask,
+                true_values.to_repr(),
+                false_values.to_repr(),
+            ))
         }
     }
 }
@@ -77,55 +85,77 @@ where
     #[inline]
     fn select(self, true_values: Simd<T, N>, false_values: Simd<T, N>) -> Simd<T, N> {
         const {
-            assert!(N <= 64, "number of elements can't be greater than 64");
+            assert!(
+                N <= 64,
+                "number of elements can't be greater than 64, use `Mask::from_bitmask_array` instead"
+            );
         }
+        select_bitmask(self, true_values, false_values)
+    }
+}
 
-        #[inline]
-        unsafe fn select_impl<T, U: FixEndianness, const M: usize, const N: usize>(
-            bitmask: U,
-            true_values: Simd<T, N>,
-            false_values: Simd<T, N>,
-        ) -> Simd<T, N>
-        where
-            T: SimdElement,
-        {
-            let default = true_values[0];
-            let true_values = true_values.resize::<M>(default);
-            let false_values = false_values.resize::<M>(default);
+/// Selects elements with a `u64` bitmask.
+///
+/// Only vectors with at most 64 elements are selected correctly, so callers must handle wider
+/// vectors separately.
+#[inline]
+pub(crate) fn select_bitmask<T, const N: usize>(
+    bitmask: u64,
+    true_values: Simd<T, N>,
+    false_values: Simd<T, N>,
+) -> Simd<T, N>
+where
+    T: SimdElement,
+{
+    #[inline]
+    unsafe fn select_impl<T, U: FixEndianness, const M: usize, const N: usize>(
+        bitmask: U,
+        true_values: Simd<T, N>,
+        false_values: Simd<T, N>,
+    ) -> Simd<T, N>
+    where
+        T: SimdElement,
+    {
+        let default = true_values[0];
+        let true_values = true_values.resize::<M>(default);
+        let false_values = false_values.resize::<M>(default);
 
-            // LLVM assumes bit order should match endianness
-            let bitmask = bitmask.fix_endianness();
+        // LLVM assumes bit order should match endianness
+        let bitmask = bitmask.fix_endianness();
 
-            // Safety: the caller guarantees that the size of U matches M
-            let selected = unsafe {
-                core::intrinsics::simd::simd_select_bitmask(bitmask, true_values, false_values)
-            };
+        // Safety: the caller guarantees that the size of U matches M
+        let selected = Simd::from_repr(unsafe {
+            core::intrinsics::simd::simd_select_bitmask(
+                bitmask,
+                true_values.to_repr(),
+                false_values.to_repr(),
+            )
+        });
 
-            selected.resize::<N>(default)
-        }
+        selected.resize::<N>(default)
+    }
 
-        // TODO modify simd_bitmask_select to truncate input, making this unnecessary
-        if N <= 8 {
-            let bitmask = self as u8;
-            // Safety: bitmask matches length
-            unsafe { select_impl::<T, u8, 8, N>(bitmask, true_values, false_values) }
-        } else if N <= 16 {
-            let bitmask = self as u16;
-            // Safety: bitmask matches length
-            unsafe { select_impl::<T, u16, 16, N>(bitmask, true_values, false_values) }
-        } else if N <= 32 {
-            let bitmask = self as u32;
-            // Safety: bitmask matches length
-            unsafe { select_impl::<T, u32, 32, N>(bitmask, true_values, false_values) }
-        } else {
-            let bitmask = self;
-            // Safety: bitmask matches length
-            unsafe { select_impl::<T, u64, 64, N>(bitmask, true_values, false_values) }
-        }
+    // TODO modify simd_bitmask_select to truncate input, making this unnecessary
+    if N <= 8 {
+        let bitmask = bitmask as u8;
+        // Safety: bitmask matches length
+        unsafe { select_impl::<T, u8, 8, N>(bitmask, true_values, false_values) }
+    } else if N <= 16 {
+        let bitmask = bitmask as u16;
+        // Safety: bitmask matches length
+        unsafe { select_impl::<T, u16, 16, N>(bitmask, true_values, false_values) }
+    } else if N <= 32 {
+        let bitmask = bitmask as u32;
+        // Safety: bitmask matches length
+        unsafe { select_impl::<T, u32, 32, N>(bitmask, true_values, false_values) }
+    } else {
+        // Safety: bitmask matches length
+        unsafe { select_impl::<T, u64, 64, N>(bitmask, true_values, false_values) }
     }
 }
 
-impl<T, U, const N: usize> Select<Mask<T, N>> for Mask<U, N>
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+impl<T, U, const N: usize> const Select<Mask<T, N>> for Mask<U, N>
 where
     T: MaskElement,
     U: MaskElement,
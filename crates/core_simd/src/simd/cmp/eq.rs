@@ -4,7 +4,8 @@ use crate::simd::{
 };
 
 /// Parallel `PartialEq`.
-pub trait SimdPartialEq {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const trait SimdPartialEq {
     /// The mask type returned by each comparison.
     type Mask;
 
@@ -20,7 +21,8 @@ pub trait SimdPartialEq {
 macro_rules! impl_number {
     { $($number:ty),* } => {
         $(
-        impl<const N: usize> SimdPartialEq for Simd<$number, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdPartialEq for Simd<$number, N>
         {
             type Mask = Mask<<$number as SimdElement>::Mask, N>;
 
@@ -42,12 +44,13 @@ macro_rules! impl_number {
     }
 }
 
-impl_number! { f16, f32, f64, u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_number! { f16, f32, f64, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
 
 macro_rules! impl_mask {
     { $($integer:ty),* } => {
         $(
-        impl<const N: usize> SimdPartialEq for Mask<$integer, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdPartialEq for Mask<$integer, N>
         {
             type Mask = Self;
 
@@ -69,7 +72,7 @@ macro_rules! impl_mask {
     }
 }
 
-impl_mask! { i8, i16, i32, i64, isize }
+impl_mask! { i8, i16, i32, i64, i128, isize }
 
 impl<T, const N: usize> SimdPartialEq for Simd<*const T, N> {
     type Mask = Mask<isize, N>;
@@ -5,7 +5,8 @@ use crate::simd::{
 };
 
 /// Parallel `PartialOrd`.
-pub trait SimdPartialOrd: SimdPartialEq {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const trait SimdPartialOrd: [const] SimdPartialEq {
     /// Test if each element is less than the corresponding element in `other`.
     #[must_use = "method returns a new mask and does not mutate the original value"]
     fn simd_lt(self, other: Self) -> Self::Mask;
@@ -24,7 +25,8 @@ pub trait SimdPartialOrd: SimdPartialEq {
 }
 
 /// Parallel `Ord`.
-pub trait SimdOrd: SimdPartialOrd {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const trait SimdOrd: [const] SimdPartialOrd {
     /// Returns the element-wise maximum with `other`.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn simd_max(self, other: Self) -> Self;
@@ -48,7 +50,8 @@ pub trait SimdOrd: SimdPartialOrd {
 macro_rules! impl_integer {
     { $($integer:ty),* } => {
         $(
-        impl<const N: usize> SimdPartialOrd for Simd<$integer, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdPartialOrd for Simd<$integer, N>
         {
             #[inline]
             fn simd_lt(self, other: Self) -> Self::Mask {
@@ -79,7 +82,8 @@ macro_rules! impl_integer {
             }
         }
 
-        impl<const N: usize> SimdOrd for Simd<$integer, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdOrd for Simd<$integer, N>
         {
             #[inline]
             fn simd_max(self, other: Self) -> Self {
@@ -105,12 +109,13 @@ macro_rules! impl_integer {
     }
 }
 
-impl_integer! { u8, u16, u32, u64, usize, i8, i16, i32, i64, isize }
+impl_integer! { u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize }
 
 macro_rules! impl_float {
     { $($float:ty),* } => {
         $(
-        impl<const N: usize> SimdPartialOrd for Simd<$float, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdPartialOrd for Simd<$float, N>
         {
             #[inline]
             fn simd_lt(self, other: Self) -> Self::Mask {
@@ -149,7 +154,8 @@ impl_float! { f16, f32, f64 }
 macro_rules! impl_mask {
     { $($integer:ty),* } => {
         $(
-        impl<const N: usize> SimdPartialOrd for Mask<$integer, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdPartialOrd for Mask<$integer, N>
         {
             #[inline]
             fn simd_lt(self, other: Self) -> Self::Mask {
@@ -180,7 +186,8 @@ macro_rules! impl_mask {
             }
         }
 
-        impl<const N: usize> SimdOrd for Mask<$integer, N>
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdOrd for Mask<$integer, N>
         {
             #[inline]
             fn simd_max(self, other: Self) -> Self {
@@ -206,7 +213,7 @@ macro_rules! impl_mask {
     }
 }
 
-impl_mask! { i8, i16, i32, i64, isize }
+impl_mask! { i8, i16, i32, i64, i128, isize }
 
 impl<T, const N: usize> SimdPartialOrd for Simd<*const T, N> {
     #[inline]
@@ -0,0 +1,110 @@
+//! Vectors with the native width of the target.
+//!
+//! The vectors in this module are as wide as the widest vector registers enabled by the
+//! target features, so the same code uses wider vectors when compiled with e.g.
+//! `-C target-feature=+avx2`.  Unlike the aliases in [`crate::simd`], their lengths differ
+//! between targets, so code using them should not assume a particular length.
+//!
+//! # Examples
+//!
+//! ```
+//! # #![feature(portable_simd)]
+//! # #[cfg(feature = "as_crate")] use core_simd::simd;
+//! # #[cfg(not(feature = "as_crate"))] use core::simd;
+//! # use simd::native::{self, f32xN};
+//! fn sum(slice: &[f32]) -> f32 {
+//!     let mut chunks = slice.chunks_exact(native::lanes::<f32>());
+//!     let mut acc = f32xN::splat(0.0);
+//!     for chunk in &mut chunks {
+//!         acc += f32xN::from_slice(chunk);
+//!     }
+//!     acc.as_array().iter().chain(chunks.remainder()).sum()
+//! }
+//!
+//! let values: Vec<f32> = (0..100).map(|x| x as f32).collect();
+//! assert_eq!(sum(&values), 4950.0);
+//! ```
+
+use crate::simd::{SimdVector, SimdWidth, bf16};
+
+/// The width in bits of the widest vector registers enabled by the target features.
+///
+/// This is 512 with AVX-512F and AVX-512BW, 256 with AVX2 or LASX, and 128 otherwise, including
+/// on targets without vector registers.
+///
+/// The width is the same for every element type, so 256-bit vectors require AVX2 even though
+/// AVX alone provides 256-bit registers for floats.  With only AVX enabled, use
+/// `<f32 as SimdWidth<256>>::Vector` (or [`f32x8`](crate::simd::f32x8)) explicitly.  Likewise,
+/// 512-bit vectors require AVX-512BW, since AVX-512F alone has no 512-bit byte or word integer
+/// operations.
+pub const NATIVE_BITS: usize = if cfg!(all(target_feature = "avx512f", target_feature = "avx512bw"))
+{
+    512
+} else if cfg!(any(target_feature = "avx2", target_feature = "lasx")) {
+    256
+} else {
+    128
+};
+
+/// A SIMD vector of `T` that is [`NATIVE_BITS`] wide.
+pub type Native<T> = <T as SimdWidth<NATIVE_BITS>>::Vector;
+
+/// Returns the number of elements of type `T` in a native vector.
+///
+/// This takes the place of a generic `NATIVE_LANES::<T>` constant, since constants can't have
+/// generic parameters.  It is a `const fn`, so it can still be used to define constants.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::native;
+/// const LANES: usize = native::lanes::<u16>();
+/// assert_eq!(LANES * 16, native::NATIVE_BITS);
+/// ```
+#[inline]
+#[must_use]
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const fn lanes<T>() -> usize
+where
+    T: SimdWidth<NATIVE_BITS>,
+{
+    <Native<T> as SimdVector>::LANES
+}
+
+macro_rules! native_alias {
+    { $($element_ty:ty = $alias:ident),* } => {
+        $(
+        #[doc = concat!("A SIMD vector of [`", stringify!($element_ty), "`] with the native width of the target.")]
+        #[allow(non_camel_case_types)]
+        pub type $alias = Native<$element_ty>;
+        )*
+    }
+}
+
+macro_rules! native_mask_alias {
+    { $($element_ty:ty : $size:literal = $alias:ident),* } => {
+        $(
+        #[doc = concat!("A SIMD mask for native vectors with ", $size, " element types.")]
+        #[allow(non_camel_case_types)]
+        pub type $alias = <Native<$element_ty> as SimdVector>::Mask;
+        )*
+    }
+}
+
+native_alias! {
+    i8 = i8xN, i16 = i16xN, i32 = i32xN, i64 = i64xN, i128 = i128xN, isize = isizexN,
+    u8 = u8xN, u16 = u16xN, u32 = u32xN, u64 = u64xN, u128 = u128xN, usize = usizexN,
+    bf16 = bf16xN, f16 = f16xN, f32 = f32xN, f64 = f64xN
+}
+
+native_mask_alias! {
+    i8 : "8-bit" = mask8xN,
+    i16 : "16-bit" = mask16xN,
+    i32 : "32-bit" = mask32xN,
+    i64 : "64-bit" = mask64xN,
+    i128 : "128-bit" = mask128xN,
+    isize : "pointer-sized" = masksizexN
+}
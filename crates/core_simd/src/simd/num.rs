@@ -1,9 +1,13 @@
 //! Traits for vectors with numeric elements.
 
+mod common;
 mod float;
 mod int;
 mod uint;
+mod widen;
 
+pub use common::*;
 pub use float::*;
 pub use int::*;
 pub use uint::*;
+pub use widen::*;
@@ -0,0 +1,228 @@
+use crate::simd::{
+    Simd, SimdElement,
+    cmp::{SimdOrd, SimdPartialOrd},
+    num::{SimdFloat, SimdInt, SimdUint},
+};
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// Operations on SIMD vectors of any numeric element type.
+///
+/// This trait is implemented for vectors of signed integers, unsigned integers, and floats, so
+/// that code can be written once for all of them.  Each method behaves like the method of the
+/// same name in [`SimdInt`], [`SimdUint`], [`SimdFloat`], or [`SimdOrd`].
+///
+/// Because the method names are shared with those traits, this trait is not included in the
+/// prelude.  Importing it alongside the prelude makes calls on concrete vector types
+/// ambiguous, so it is best used only as a bound in generic code.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{Simd, num::SimdNum};
+/// fn dot<V: SimdNum>(a: &[V], b: &[V]) -> V::Scalar {
+///     let mut sum = V::ZERO;
+///     for (a, b) in a.iter().zip(b) {
+///         sum += *a * *b;
+///     }
+///     sum.reduce_sum()
+/// }
+///
+/// assert_eq!(dot(&[Simd::from_array([1, 2, 3, 4])], &[Simd::splat(2)]), 20);
+/// assert_eq!(dot(&[Simd::from_array([0.5f32, 1.5])], &[Simd::splat(2.0)]), 4.0);
+/// ```
+pub impl(self) trait SimdNum:
+    Copy
+    + PartialEq
+    + SimdPartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// Scalar type contained by this SIMD vector type.
+    type Scalar: SimdElement;
+
+    /// A vector with every element set to zero.
+    const ZERO: Self;
+
+    /// A vector with every element set to one.
+    const ONE: Self;
+
+    /// Constructs a vector with every element set to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Simd, num::SimdNum};
+    /// fn axpy<V: SimdNum>(alpha: V::Scalar, x: &[V], y: &mut [V]) {
+    ///     let alpha = V::splat(alpha);
+    ///     for (x, y) in x.iter().zip(y) {
+    ///         *y += alpha * *x;
+    ///     }
+    /// }
+    ///
+    /// let mut y = [Simd::from_array([1, 2, 3, 4])];
+    /// axpy(2, &[Simd::splat(10)], &mut y);
+    /// assert_eq!(y[0].to_array(), [21, 22, 23, 24]);
+    /// ```
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// Returns the absolute value of each element.
+    ///
+    /// Unsigned elements are returned unchanged.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn abs(self) -> Self;
+
+    /// Returns the elementwise minimum with `other`.
+    ///
+    /// For floats, if one of the values is NaN, then the other value is returned.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn simd_min(self, other: Self) -> Self;
+
+    /// Returns the elementwise maximum with `other`.
+    ///
+    /// For floats, if one of the values is NaN, then the other value is returned.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn simd_max(self, other: Self) -> Self;
+
+    /// Restrict each element to a certain interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max` on any element.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn simd_clamp(self, min: Self, max: Self) -> Self;
+
+    /// Returns the sum of the elements of the vector.
+    ///
+    /// Integer addition wraps, and floats are added in order.
+    fn reduce_sum(self) -> Self::Scalar;
+
+    /// Returns the product of the elements of the vector.
+    ///
+    /// Integer multiplication wraps, and floats are multiplied in order.
+    fn reduce_product(self) -> Self::Scalar;
+
+    /// Returns the maximum element in the vector.
+    ///
+    /// For floats, NaN elements are ignored, and the result is NaN only if all elements are
+    /// NaN.
+    fn reduce_max(self) -> Self::Scalar;
+
+    /// Returns the minimum element in the vector.
+    ///
+    /// For floats, NaN elements are ignored, and the result is NaN only if all elements are
+    /// NaN.
+    fn reduce_min(self) -> Self::Scalar;
+}
+
+/// Operations on SIMD vectors of signed integers or floats.
+pub impl(self) trait SimdSigned: SimdNum + Neg<Output = Self> {
+    /// Returns a vector of each element's sign.
+    ///
+    /// For integers, each element is `-1`, `0`, or `1`.  For floats, each element is `-1.0` or
+    /// `1.0` with the sign of the element, or NaN if the element is NaN.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_num {
+    { $trait:ident, $order:ident; $($ty:ty),* } => {
+        $(
+        impl<const N: usize> SimdNum for Simd<$ty, N> {
+            type Scalar = $ty;
+
+            const ZERO: Self = Self::splat(0 as $ty);
+            const ONE: Self = Self::splat(1 as $ty);
+
+            #[inline]
+            fn splat(value: $ty) -> Self {
+                Simd::splat(value)
+            }
+
+            impl_num! { @abs $trait }
+
+            #[inline]
+            fn simd_min(self, other: Self) -> Self {
+                $order::simd_min(self, other)
+            }
+
+            #[inline]
+            fn simd_max(self, other: Self) -> Self {
+                $order::simd_max(self, other)
+            }
+
+            #[inline]
+            fn simd_clamp(self, min: Self, max: Self) -> Self {
+                $order::simd_clamp(self, min, max)
+            }
+
+            #[inline]
+            fn reduce_sum(self) -> $ty {
+                $trait::reduce_sum(self)
+            }
+
+            #[inline]
+            fn reduce_product(self) -> $ty {
+                $trait::reduce_product(self)
+            }
+
+            #[inline]
+            fn reduce_max(self) -> $ty {
+                $trait::reduce_max(self)
+            }
+
+            #[inline]
+            fn reduce_min(self) -> $ty {
+                $trait::reduce_min(self)
+            }
+        }
+        )*
+    };
+    { @abs SimdUint } => {
+        #[inline]
+        fn abs(self) -> Self {
+            self
+        }
+    };
+    { @abs $trait:ident } => {
+        #[inline]
+        fn abs(self) -> Self {
+            $trait::abs(self)
+        }
+    };
+}
+
+macro_rules! impl_signed {
+    { $trait:ident; $($ty:ty),* } => {
+        $(
+        impl<const N: usize> SimdSigned for Simd<$ty, N> {
+            #[inline]
+            fn signum(self) -> Self {
+                $trait::signum(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_num! { SimdInt, SimdOrd; i8, i16, i32, i64, i128, isize }
+impl_num! { SimdUint, SimdOrd; u8, u16, u32, u64, u128, usize }
+impl_num! { SimdFloat, SimdFloat; f16, f32, f64 }
+
+impl_signed! { SimdInt; i8, i16, i32, i64, i128, isize }
+impl_signed! { SimdFloat; f16, f32, f64 }
@@ -1,10 +1,25 @@
+use crate::core_simd::estimate::{Estimate, Sqrt};
 use crate::simd::{
     Mask, Select, Simd, SimdCast, SimdElement,
-    cmp::{SimdPartialEq, SimdPartialOrd},
+    cmp::{SimdOrd, SimdPartialEq, SimdPartialOrd},
 };
 
+/// Rounding modes for converting floats to integers, used by [`SimdFloat::to_int_round`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round to the nearest integer, with ties rounding to the even integer.
+    NearestEven,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    Truncate,
+}
+
 /// Operations on SIMD vectors of floats.
-pub impl(self) trait SimdFloat: Copy {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub impl(self) const trait SimdFloat: Copy {
     /// Mask type used for manipulating this SIMD vector type.
     type Mask;
 
@@ -61,6 +76,57 @@ pub impl(self) trait SimdFloat: Copy {
     where
         Self::Scalar: core::convert::FloatToInt<I>;
 
+    /// Rounds each element to an integer with the given rounding mode, and converts it to
+    /// another SIMD-valid type.
+    ///
+    /// Each element is rounded as a float and then converted, which is exact because the
+    /// rounded value is an integer.  Like [`cast`](Self::cast), out-of-range values saturate to
+    /// the limits of `I`, and NaN converts to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// # use simd::num::RoundingMode;
+    /// let x = f32x4::from_array([2.5, -2.5, 1e10, f32::NAN]);
+    /// assert_eq!(x.to_int_round::<i32>(RoundingMode::NearestEven), i32x4::from_array([2, -2, i32::MAX, 0]));
+    /// assert_eq!(x.to_int_round::<i32>(RoundingMode::Floor), i32x4::from_array([2, -3, i32::MAX, 0]));
+    /// assert_eq!(x.to_int_round::<i32>(RoundingMode::Ceil), i32x4::from_array([3, -2, i32::MAX, 0]));
+    /// assert_eq!(x.to_int_round::<i32>(RoundingMode::Truncate), i32x4::from_array([2, -2, i32::MAX, 0]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn to_int_round<I: SimdCast>(self, mode: RoundingMode) -> Self::Cast<I>
+    where
+        Self::Scalar: core::convert::FloatToInt<I>;
+
+    /// Rounds each element to an integer with the given rounding mode, and converts it to
+    /// another SIMD-valid type, also returning a mask of the elements that were out of range.
+    ///
+    /// The converted vector is the same as [`to_int_round`](Self::to_int_round).  The mask
+    /// is set for each element that is NaN, or that is outside the range of `I` after
+    /// rounding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// # use simd::num::RoundingMode;
+    /// let x = f32x4::from_array([127.4, 127.6, -128.5, f32::NAN]);
+    /// let (ints, clipped) = x.to_int_round_checked::<i8>(RoundingMode::NearestEven);
+    /// assert_eq!(ints, i8x4::from_array([127, 127, -128, 0]));
+    /// assert_eq!(clipped.to_array(), [false, true, false, true]);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn to_int_round_checked<I: SimdCast>(self, mode: RoundingMode) -> (Self::Cast<I>, Self::Mask)
+    where
+        Self::Scalar: core::convert::FloatToInt<I>;
+
     /// Raw transmutation to an unsigned integer vector type with the
     /// same size and number of elements.
     #[must_use = "method returns a new vector and does not mutate the original value"]
@@ -153,6 +219,241 @@ pub impl(self) trait SimdFloat: Copy {
     #[must_use = "method returns a new vector and does not mutate the original value"]
     fn simd_clamp(self, min: Self, max: Self) -> Self;
 
+    /// Elementwise fused multiply-add. Computes `(self * a) + b` with only one rounding error,
+    /// yielding a more accurate result than an unfused multiply-add.
+    ///
+    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
+    /// architecture has a dedicated `fma` CPU instruction.  However, this is not always
+    /// true, and will be heavily dependent on designing algorithms with specific target
+    /// hardware in mind.
+    ///
+    /// Targets without an `fma` instruction call the `fmaf` or `fma` library function for each
+    /// element.  These are correctly rounded software implementations, provided by the system
+    /// math library or, on `no_std` targets, by `compiler_builtins`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([1.0, 2.0, 3.0, 4.0]);
+    /// let y = x.mul_add(f32x4::splat(2.0), f32x4::splat(0.5));
+    /// assert_eq!(y, f32x4::from_array([2.5, 4.5, 6.5, 8.5]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Produces a vector where every element has the square root value
+    /// of the equivalently-indexed element in `self`.
+    ///
+    /// The result is correctly rounded. Targets without a square root instruction call the
+    /// `sqrtf` or `sqrt` library function for each element, which is provided by the system
+    /// math library or, on `no_std` targets, by `compiler_builtins`.  Const evaluation computes
+    /// the same correctly rounded result in software.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([4.0, 9.0, 0.0, -1.0]);
+    /// let y = x.sqrt();
+    /// assert_eq!(y.as_array()[..3], [2.0, 3.0, 0.0]);
+    /// assert!(y[3].is_nan());
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sqrt(self) -> Self;
+
+    /// Returns an estimate of the reciprocal (inverse) of each element, `1/x`.
+    ///
+    /// When the target has a reciprocal estimate instruction (such as `rcpps` on x86 or
+    /// `frecpe` on AArch64), it is used, and each element has a relative error of at most
+    /// `2^-8`.  Otherwise, the result is the same as [`recip`](Self::recip).  The result
+    /// may vary between targets, and subnormal elements may be treated as zero.
+    ///
+    /// Const evaluation can't execute the target's instruction, so it behaves like a target
+    /// without one and produces the exact result.  This stays within the documented error
+    /// bound, and keeps the method usable in const code, unlike panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([1.0, 2.0, 4.0, -8.0]);
+    /// let error = (x.recip_estimate() * x - f32x4::splat(1.0)).abs();
+    /// assert!(error.simd_le(f32x4::splat(1.0 / 256.0)).all());
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn recip_estimate(self) -> Self;
+
+    /// Returns an estimate of the reciprocal (inverse) of each element, `1/x`, refined
+    /// with Newton-Raphson iteration.
+    ///
+    /// For `f32`, each element has a relative error of at most `2^-21`.  Other element types
+    /// currently produce the same result as [`recip`](Self::recip).  As with
+    /// [`recip_estimate`](Self::recip_estimate), the result may vary between targets.
+    ///
+    /// Estimates may be flushed to zero or overflow for elements with a magnitude outside
+    /// `[2^-126, 2^126]`, so if any element is outside that range, those elements produce
+    /// the same result as [`recip`](Self::recip), which is slower.
+    ///
+    /// Zeros produce infinities of the same sign, and infinities produce zeros.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn recip_fast(self) -> Self;
+
+    /// Returns an estimate of the reciprocal of the square root of each element, `1/sqrt(x)`.
+    ///
+    /// When the target has a reciprocal square root estimate instruction (such as `rsqrtps`
+    /// on x86 or `frsqrte` on AArch64), it is used, and each element has a relative error of at
+    /// most `2^-8`.  Otherwise, the result is the same as `self.sqrt().recip()`.  The result
+    /// may vary between targets, and subnormal elements may be treated as zero.  Like
+    /// [`recip_estimate`](Self::recip_estimate), const evaluation produces the exact result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([1.0, 4.0, 16.0, 64.0]);
+    /// let expected = f32x4::from_array([1.0, 0.5, 0.25, 0.125]);
+    /// let error = (x.rsqrt_estimate() / expected - f32x4::splat(1.0)).abs();
+    /// assert!(error.simd_le(f32x4::splat(1.0 / 256.0)).all());
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn rsqrt_estimate(self) -> Self;
+
+    /// Returns an estimate of the reciprocal of the square root of each element, `1/sqrt(x)`,
+    /// refined with Newton-Raphson iteration.
+    ///
+    /// For `f32`, each element has a relative error of at most `2^-21`.  Other element types
+    /// currently produce the same result as `self.sqrt().recip()`.  As with
+    /// [`rsqrt_estimate`](Self::rsqrt_estimate), the result may vary between targets.
+    ///
+    /// Like [`recip_fast`](Self::recip_fast), elements with a magnitude outside
+    /// `[2^-126, 2^126]` produce the same result as `self.sqrt().recip()`.
+    ///
+    /// Zeros produce infinities of the same sign, positive infinity produces zero, and
+    /// negative elements produce NaN.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn rsqrt_fast(self) -> Self;
+
+    /// Decomposes each element into a normalized fraction and an integral power of two.
+    ///
+    /// For each element `x`, returns `m` and `e` such that `x = m * 2^e`, where the magnitude
+    /// of `m` is in the range `[0.5, 1)`.  Subnormal elements are normalized.  Zeros,
+    /// infinities, and NaN are returned unchanged, with an exponent of `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([8.0, -0.75, 0.0, f32::INFINITY]);
+    /// let (m, e) = x.frexp();
+    /// assert_eq!(m, f32x4::from_array([0.5, -0.75, 0.0, f32::INFINITY]));
+    /// assert_eq!(e, i32x4::from_array([4, 0, 0, 0]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn frexp(self) -> (Self, Self::Cast<i32>);
+
+    /// Multiplies each element by 2 raised to the power of the equivalently-indexed element
+    /// in `exp`.
+    ///
+    /// The result is exact unless it overflows to infinity or is subnormal, in which case it
+    /// is rounded once.  Zeros, infinities, and NaN are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([0.5, 3.0, 1.0, 1.0]);
+    /// let exp = i32x4::from_array([4, -1, 128, -149]);
+    /// let y = x.ldexp(exp);
+    /// assert_eq!(y, f32x4::from_array([8.0, 1.5, f32::INFINITY, f32::from_bits(1)]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ldexp(self, exp: Self::Cast<i32>) -> Self;
+
+    /// Multiplies each element by 2 raised to the power of the equivalently-indexed element
+    /// in `n`.
+    ///
+    /// This is the same as [`ldexp`](Self::ldexp).
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn scalbn(self, n: Self::Cast<i32>) -> Self;
+
+    /// Returns the unbiased exponent of each element, the floor of the base 2 logarithm of
+    /// its magnitude.
+    ///
+    /// Subnormal elements are normalized.  Zeros and NaN produce [`i32::MIN`], and
+    /// infinities produce [`i32::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([8.0, -0.75, 0.0, f32::from_bits(1)]);
+    /// assert_eq!(x.ilogb(), i32x4::from_array([3, -1, i32::MIN, -149]));
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ilogb(self) -> Self::Cast<i32>;
+
+    /// Returns the least number greater than each element.
+    ///
+    /// This has the same semantics as the scalar `next_up`: NaN and positive infinity are
+    /// returned unchanged, zeros produce the smallest positive subnormal, and the largest
+    /// finite value produces positive infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([1.0, -0.0, f32::MAX, f32::NEG_INFINITY]);
+    /// let y = f32x4::from_array([1.0 + f32::EPSILON, f32::from_bits(1), f32::INFINITY, f32::MIN]);
+    /// assert_eq!(x.next_up(), y);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn next_up(self) -> Self;
+
+    /// Returns the greatest number less than each element.
+    ///
+    /// This has the same semantics as the scalar `next_down`: NaN and negative infinity are
+    /// returned unchanged, zeros produce the smallest negative subnormal, and the smallest
+    /// finite value produces negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = f32x4::from_array([1.0, 0.0, f32::MIN, f32::INFINITY]);
+    /// let y = f32x4::from_array([1.0 - f32::EPSILON / 2.0, -f32::from_bits(1), f32::NEG_INFINITY, f32::MAX]);
+    /// assert_eq!(x.next_down(), y);
+    /// ```
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn next_down(self) -> Self;
+
     /// Returns the sum of the elements of the vector.
     ///
     /// # Examples
@@ -239,7 +540,142 @@ pub impl(self) trait SimdFloat: Copy {
 macro_rules! impl_trait {
     { $($ty:ty { bits: $bits_ty:ty, mask: $mask_ty:ty }),* } => {
         $(
-        impl<const N: usize> SimdFloat for Simd<$ty, N>
+        impl<const N: usize> Simd<$ty, N> {
+            /// Number of explicitly stored mantissa bits.
+            const MANTISSA_BITS: $bits_ty = <$ty>::MANTISSA_DIGITS as $bits_ty - 1;
+            /// Mask of the exponent bits.
+            const EXP_FIELD: $bits_ty = <$ty>::INFINITY.to_bits();
+            const SIGN_BIT: $bits_ty = (-0.0 as $ty).to_bits();
+            /// Power of two that scales any subnormal to a normal number.
+            const SCALE_EXP: i32 = <$ty>::MANTISSA_DIGITS as i32 + 3;
+            const SCALE: $ty = <$ty>::from_bits(
+                ((Self::SCALE_EXP + <$ty>::MAX_EXP - 1) as $bits_ty) << Self::MANTISSA_BITS,
+            );
+            const SCALE_RECIP: $ty = <$ty>::from_bits(
+                ((<$ty>::MAX_EXP - 1 - Self::SCALE_EXP) as $bits_ty) << Self::MANTISSA_BITS,
+            );
+            /// Smallest power of two with no fractional bits.
+            const ROUND_MAGIC: $ty = <$ty>::from_bits(
+                (Self::MANTISSA_BITS + (<$ty>::MAX_EXP - 1) as $bits_ty) << Self::MANTISSA_BITS,
+            );
+            /// Whether the target has instructions for rounding vectors to integers.
+            const HAS_ROUND: bool = cfg!(any(
+                target_feature = "sse4.1",
+                all(target_arch = "aarch64", target_feature = "neon"),
+                target_feature = "simd128",
+            ));
+
+            /// The range of integer type `I`, as a lower bound and an exclusive upper bound.
+            ///
+            /// This is intended to be evaluated at compile time.  Bounds that overflow are
+            /// infinite.
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            const fn int_bounds<I: SimdCast>() -> ($ty, $ty) {
+                const fn pow2(exp: u32) -> $ty {
+                    if exp as i32 >= <$ty>::MAX_EXP {
+                        <$ty>::INFINITY
+                    } else {
+                        <$ty>::from_bits(
+                            ((exp as i32 + <$ty>::MAX_EXP - 1) as $bits_ty) << Simd::<$ty, 1>::MANTISSA_BITS,
+                        )
+                    }
+                }
+                if I::SIGNED {
+                    (-pow2(I::BITS - 1), pow2(I::BITS - 1))
+                } else {
+                    (0.0, pow2(I::BITS))
+                }
+            }
+
+            /// Returns which elements have a magnitude in `[MIN_POSITIVE, 1 / MIN_POSITIVE]`,
+            /// where reciprocal estimates and their refinements are normal.
+            #[inline]
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            const fn estimate_in_range(self) -> Mask<$mask_ty, N> {
+                let abs = self.abs();
+                abs.simd_ge(Self::splat(<$ty>::MIN_POSITIVE))
+                    & abs.simd_le(Self::splat(1.0 / <$ty>::MIN_POSITIVE))
+            }
+
+            /// Replaces the exponent of each element of a mantissa returned by `frexp`.
+            #[inline]
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            const fn with_exp(mantissa: Self, exp: Simd<i32, N>) -> Self {
+                // Safety: supported types are guaranteed by SimdCast
+                let exp: Simd<$bits_ty, N> = unsafe {
+                    core::intrinsics::simd::simd_as(exp + Simd::splat(<$ty>::MAX_EXP - 2))
+                };
+                Self::from_bits(
+                    (mantissa.to_bits() & Simd::splat(!Self::EXP_FIELD)) | (exp << Self::MANTISSA_BITS),
+                )
+            }
+
+            /// Rounds each element to an integer with the given rounding mode.
+            #[inline]
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            const fn round_with(self, mode: RoundingMode) -> Self {
+                if Self::HAS_ROUND {
+                    // These lower to single instructions, such as `roundps` on x86 and
+                    // `frintn`/`frintm`/`frintp`/`frintz` on AArch64, which also fold into
+                    // `fcvtns`/`fcvtms`/`fcvtps`/`fcvtzs` when followed by a conversion.
+                    // Safety: `self` is a float vector
+                    return unsafe {
+                        use core::intrinsics::simd::*;
+                        match mode {
+                            RoundingMode::NearestEven => simd_round_ties_even(self),
+                            RoundingMode::Floor => simd_floor(self),
+                            RoundingMode::Ceil => simd_ceil(self),
+                            RoundingMode::Truncate => simd_trunc(self),
+                        }
+                    };
+                }
+
+                // Otherwise the intrinsics are library calls for each element.
+                // Adding and subtracting the magic number rounds off the fractional bits, with
+                // ties to even.  Larger values, infinities, and NaN are already integers.
+                let magic = Self::splat(Self::ROUND_MAGIC);
+                let abs = self.abs();
+                let nearest = abs.simd_lt(magic).select(((abs + magic) - magic).copysign(self), self);
+                let one = Self::splat(1.0);
+                match mode {
+                    RoundingMode::NearestEven => nearest,
+                    RoundingMode::Floor => nearest - nearest.simd_gt(self).select(one, Self::splat(0.0)),
+                    RoundingMode::Ceil => nearest + nearest.simd_lt(self).select(one, Self::splat(0.0)),
+                    RoundingMode::Truncate => {
+                        nearest - nearest.abs().simd_gt(abs).select(one.copysign(self), Self::splat(0.0))
+                    }
+                }
+            }
+
+            /// Converts elements that are already integers, saturating like [`cast`](SimdFloat::cast).
+            #[inline]
+            #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+            const fn rounded_to_int<I: SimdCast>(self) -> Simd<I, N>
+            where
+                $ty: core::convert::FloatToInt<I>,
+            {
+                if cfg!(any(target_arch = "x86", target_arch = "x86_64")) {
+                    // x86 has no saturating conversions, and `cast` converts each element
+                    // separately, so convert in range with `cvttps2dq` and the like and saturate
+                    // with selects instead
+                    let (min, max) = const { Self::int_bounds::<I>() };
+                    let above = self.simd_ge(Self::splat(max));
+                    let below = self.simd_lt(Self::splat(min));
+                    let in_range = !(above | below) & self.simd_eq(self);
+                    let x = in_range.select(self, Self::splat(0.0));
+                    // Safety: the converted elements are finite and in range of `I`
+                    let ints: Simd<I, N> = unsafe { x.to_int_unchecked() };
+                    let lower: Simd<I, N> = Self::splat(<$ty>::NEG_INFINITY).cast();
+                    let upper: Simd<I, N> = Self::splat(<$ty>::INFINITY).cast();
+                    above.select(upper, below.select(lower, ints))
+                } else {
+                    self.cast()
+                }
+            }
+        }
+
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const SimdFloat for Simd<$ty, N>
         {
             type Mask = Mask<<$mask_ty as SimdElement>::Mask, N>;
             type Scalar = $ty;
@@ -260,25 +696,34 @@ macro_rules! impl_trait {
             #[inline]
             fn cast<T: SimdCast>(self) -> Self::Cast<T>
             {
-                const { assert!(N <= 64) };
-                if N <= 2 || N == 4 || N == 8 || N == 16 || N == 32 || N == 64 {
+                const { assert!(N <= 512) };
+                if N <= 2 || N.is_power_of_two() {
                     // Safety: supported types are guaranteed by SimdCast
                     unsafe { core::intrinsics::simd::simd_as(self) }
                 } else if N < 4 {
-                    let x = self.resize::<4>(Default::default()).cast();
-                    x.resize::<N>(x[0])
+                    let x = self.resize::<4>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
                 } else if N < 8 {
-                    let x = self.resize::<8>(Default::default()).cast();
-                    x.resize::<N>(x[0])
+                    let x = self.resize::<8>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
                 } else if N < 16 {
-                    let x = self.resize::<16>(Default::default()).cast();
-                    x.resize::<N>(x[0])
+                    let x = self.resize::<16>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
                 } else if N < 32 {
-                    let x = self.resize::<32>(Default::default()).cast();
-                    x.resize::<N>(x[0])
+                    let x = self.resize::<32>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
+                } else if N < 64 {
+                    let x = self.resize::<64>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
+                } else if N < 128 {
+                    let x = self.resize::<128>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
+                } else if N < 256 {
+                    let x = self.resize::<256>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
                 } else {
-                    let x = self.resize::<64>(Default::default()).cast();
-                    x.resize::<N>(x[0])
+                    let x = self.resize::<512>(0.0).cast();
+                    x.resize::<N>(x.as_array()[0])
                 }
             }
 
@@ -292,16 +737,40 @@ macro_rules! impl_trait {
                 unsafe { core::intrinsics::simd::simd_cast(self) }
             }
 
+            #[inline]
+            fn to_int_round<I: SimdCast>(self, mode: RoundingMode) -> Self::Cast<I>
+            where
+                Self::Scalar: core::convert::FloatToInt<I>,
+            {
+                self.round_with(mode).rounded_to_int()
+            }
+
+            #[inline]
+            fn to_int_round_checked<I: SimdCast>(self, mode: RoundingMode) -> (Self::Cast<I>, Self::Mask)
+            where
+                Self::Scalar: core::convert::FloatToInt<I>,
+            {
+                // The bounds of wide integers may overflow to infinity, so infinities are
+                // checked separately
+                let (min, max) = const { Self::int_bounds::<I>() };
+
+                let rounded = self.round_with(mode);
+                let out_of_range = !rounded.is_finite()
+                    | rounded.simd_lt(Self::splat(min))
+                    | rounded.simd_ge(Self::splat(max));
+                (rounded.rounded_to_int(), out_of_range)
+            }
+
             #[inline]
             fn to_bits(self) -> Simd<$bits_ty, N> {
-                assert_eq!(size_of::<Self>(), size_of::<Self::Bits>());
+                const { assert!(size_of::<Self>() == size_of::<Self::Bits>()) };
                 // Safety: transmuting between vector types is safe
                 unsafe { core::mem::transmute_copy(&self) }
             }
 
             #[inline]
             fn from_bits(bits: Simd<$bits_ty, N>) -> Self {
-                assert_eq!(size_of::<Self>(), size_of::<Self::Bits>());
+                const { assert!(size_of::<Self>() == size_of::<Self::Bits>()) };
                 // Safety: transmuting between vector types is safe
                 unsafe { core::mem::transmute_copy(&bits) }
             }
@@ -403,11 +872,154 @@ macro_rules! impl_trait {
                 x
             }
 
+            #[inline]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                // Safety: `self`, `a`, and `b` are float vectors
+                unsafe { core::intrinsics::simd::simd_fma(self, a, b) }
+            }
+
+            #[inline]
+            fn sqrt(self) -> Self {
+                Sqrt::sqrt(self)
+            }
+
+            #[inline]
+            fn recip_estimate(self) -> Self {
+                Estimate::recip_estimate_steps(self).0
+            }
+
+            #[inline]
+            fn recip_fast(self) -> Self {
+                let (estimate, steps) = Estimate::recip_estimate_steps(self);
+                let mut x = estimate;
+                let mut i = 0;
+                while i < steps {
+                    x = x * (Self::splat(2.0) - self * x);
+                    i += 1;
+                }
+                if steps == 0 {
+                    return x;
+                }
+                // Estimates of very large or small elements may be flushed to zero or overflow,
+                // and refining zero or infinity produces NaN
+                let in_range = Self::estimate_in_range(self);
+                if in_range.all() {
+                    x
+                } else {
+                    in_range.select(x, Self::splat(1.0) / self)
+                }
+            }
+
+            #[inline]
+            fn rsqrt_estimate(self) -> Self {
+                Estimate::rsqrt_estimate_steps(self).0
+            }
+
+            #[inline]
+            fn rsqrt_fast(self) -> Self {
+                let (estimate, steps) = Estimate::rsqrt_estimate_steps(self);
+                let mut x = estimate;
+                let mut i = 0;
+                while i < steps {
+                    // Multiply by `x` first to avoid subnormal intermediates
+                    x = x * (Self::splat(1.5) - Self::splat(0.5) * (self * x * x));
+                    i += 1;
+                }
+                if steps == 0 {
+                    return x;
+                }
+                // See `recip_fast`
+                let in_range = Self::estimate_in_range(self);
+                if in_range.all() {
+                    x
+                } else {
+                    in_range.select(x, Self::splat(1.0) / SimdFloat::sqrt(self))
+                }
+            }
+
+            #[inline]
+            fn frexp(self) -> (Self, Self::Cast<i32>) {
+                let subnormal = self.is_subnormal();
+                let x = subnormal.select(self * Self::splat(Self::SCALE), self);
+                let bits = x.to_bits();
+                // Safety: supported types are guaranteed by SimdCast
+                let exp: Simd<i32, N> = unsafe {
+                    core::intrinsics::simd::simd_as((bits & Simd::splat(Self::EXP_FIELD)) >> Self::MANTISSA_BITS)
+                };
+                let exp = exp
+                    - Simd::splat(<$ty>::MAX_EXP - 2)
+                    - subnormal.select(Simd::splat(Self::SCALE_EXP), Simd::splat(0));
+                let mantissa = Self::from_bits(
+                    (bits & Simd::splat(!Self::EXP_FIELD)) | Simd::splat((0.5 as $ty).to_bits()),
+                );
+                let special = self.simd_eq(Self::splat(0.0)) | !self.is_finite();
+                (special.select(self, mantissa), special.select(Simd::splat(0), exp))
+            }
+
+            #[inline]
+            fn ldexp(self, exp: Self::Cast<i32>) -> Self {
+                let (mantissa, e) = self.frexp();
+                // Safety: `e` and `exp` are integer vectors
+                let e = unsafe { core::intrinsics::simd::simd_saturating_add(e, exp) };
+                let normal = Self::with_exp(mantissa, e.simd_clamp(Simd::splat(<$ty>::MIN_EXP), Simd::splat(<$ty>::MAX_EXP)));
+                // Scale the mantissa to a normal number first, so that only the final
+                // multiplication rounds
+                let subnormal = Self::with_exp(
+                    mantissa,
+                    e.simd_max(Simd::splat(<$ty>::MIN_EXP - Self::SCALE_EXP)) + Simd::splat(Self::SCALE_EXP),
+                ) * Self::splat(Self::SCALE_RECIP);
+                let overflow = Self::splat(<$ty>::INFINITY).copysign(self);
+
+                let result = e.simd_lt(Simd::splat(<$ty>::MIN_EXP)).select(subnormal, normal);
+                let result = e.simd_gt(Simd::splat(<$ty>::MAX_EXP)).select(overflow, result);
+                let special = self.simd_eq(Self::splat(0.0)) | !self.is_finite();
+                special.select(self, result)
+            }
+
+            #[inline]
+            fn scalbn(self, n: Self::Cast<i32>) -> Self {
+                self.ldexp(n)
+            }
+
+            #[inline]
+            fn ilogb(self) -> Self::Cast<i32> {
+                let (_, exp) = self.frexp();
+                let result = exp - Simd::splat(1);
+                let result = (self.simd_eq(Self::splat(0.0)) | self.is_nan()).select(Simd::splat(i32::MIN), result);
+                self.is_infinite().select(Simd::splat(i32::MAX), result)
+            }
+
+            #[inline]
+            fn next_up(self) -> Self {
+                let bits = self.to_bits();
+                let abs = bits & Simd::splat(!Self::SIGN_BIT);
+                let next = bits.simd_eq(abs).select(bits + Simd::splat(1), bits - Simd::splat(1));
+                let next = abs.simd_eq(Simd::splat(0)).select(Simd::splat(1), next);
+                let unchanged = self.is_nan() | self.simd_eq(Self::splat(<$ty>::INFINITY));
+                unchanged.select(self, Self::from_bits(next))
+            }
+
+            #[inline]
+            fn next_down(self) -> Self {
+                let bits = self.to_bits();
+                let abs = bits & Simd::splat(!Self::SIGN_BIT);
+                let next = bits.simd_eq(abs).select(bits - Simd::splat(1), bits + Simd::splat(1));
+                let next = abs.simd_eq(Simd::splat(0)).select(Simd::splat(Self::SIGN_BIT | 1), next);
+                let unchanged = self.is_nan() | self.simd_eq(Self::splat(<$ty>::NEG_INFINITY));
+                unchanged.select(self, Self::from_bits(next))
+            }
+
             #[inline]
             fn reduce_sum(self) -> Self::Scalar {
                 // LLVM sum is inaccurate on i586
                 if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
-                    self.as_array().iter().sum()
+                    let mut sum = -0.;
+                    let mut i = 0;
+                    while i < N {
+                        sum += self.as_array()[i];
+                        i += 1;
+                    }
+                    sum
                 } else {
                     // Safety: `self` is a float vector
                     unsafe { core::intrinsics::simd::simd_reduce_add_ordered(self, -0.) }
@@ -418,7 +1030,13 @@ macro_rules! impl_trait {
             fn reduce_product(self) -> Self::Scalar {
                 // LLVM product is inaccurate on i586
                 if cfg!(all(target_arch = "x86", not(target_feature = "sse2"))) {
-                    self.as_array().iter().product()
+                    let mut product = 1.;
+                    let mut i = 0;
+                    while i < N {
+                        product *= self.as_array()[i];
+                        i += 1;
+                    }
+                    product
                 } else {
                     // Safety: `self` is a float vector
                     unsafe { core::intrinsics::simd::simd_reduce_mul_ordered(self, 1.) }
@@ -429,12 +1047,24 @@ macro_rules! impl_trait {
             fn reduce_max(self) -> Self::Scalar {
                 // LLVM has no intrinsic we can use here
                 // (https://github.com/llvm/llvm-project/issues/185827).
-                self.as_array().iter().copied().fold(Self::Scalar::NAN, Self::Scalar::max)
+                let mut max = Self::Scalar::NAN;
+                let mut i = 0;
+                while i < N {
+                    max = max.max(self.as_array()[i]);
+                    i += 1;
+                }
+                max
             }
 
             #[inline]
             fn reduce_min(self) -> Self::Scalar {
-                self.as_array().iter().copied().fold(Self::Scalar::NAN, Self::Scalar::min)
+                let mut min = Self::Scalar::NAN;
+                let mut i = 0;
+                while i < N {
+                    min = min.min(self.as_array()[i]);
+                    i += 1;
+                }
+                min
             }
         }
         )*
@@ -1,5 +1,6 @@
 use crate::simd::{
     Mask, Select, Simd, SimdCast, SimdElement, cmp::SimdOrd, cmp::SimdPartialOrd, num::SimdUint,
+    num::Widen,
 };
 
 /// Operations on SIMD vectors of signed integers.
@@ -23,6 +24,116 @@ pub impl(self) trait SimdInt: Copy {
     #[must_use]
     fn cast<T: SimdCast>(self) -> Self::Cast<T>;
 
+    /// Performs elementwise conversion to another SIMD-valid type, saturating at the limits of
+    /// integer types.
+    ///
+    /// Unlike [`cast`](Self::cast), elements outside the range of an integer type become its
+    /// minimum or maximum value.  Conversions to float types are the same as `cast`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = i16x4::from_array([-300, -1, 100, 300]);
+    /// assert_eq!(x.saturating_cast::<u8>(), u8x4::from_array([0, 0, 100, 255]));
+    /// assert_eq!(x.saturating_cast::<i8>(), i8x4::from_array([-128, -1, 100, 127]));
+    /// assert_eq!(x.cast::<u8>(), u8x4::from_array([212, 255, 100, 44]));
+    /// ```
+    #[must_use]
+    fn saturating_cast<T: SimdCast>(self) -> Self::Cast<T>;
+
+    /// Converts the elements of `self` followed by the elements of `other` to another
+    /// SIMD-valid type, saturating like [`saturating_cast`](Self::saturating_cast).
+    ///
+    /// `M` must be twice the number of elements in `self`, which is checked at compile time.
+    /// Packing `i16` into `u8` matches instructions like `packuswb` on x86 and `sqxtun` on
+    /// AArch64.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let a = i16x4::from_array([-1, 0, 255, 256]);
+    /// let b = i16x4::from_array([1, 2, 3, 1000]);
+    /// let packed: u8x8 = a.pack_saturating(b);
+    /// assert_eq!(packed.to_array(), [0, 0, 255, 255, 1, 2, 3, 255]);
+    /// ```
+    #[must_use]
+    fn pack_saturating<T: SimdCast, const M: usize>(self, other: Self) -> Simd<T, M>;
+
+    /// Converts the first half of the elements to a signed integer type twice as wide.
+    ///
+    /// The elements are sign-extended, and the output has half as many elements, so `i8x8` widens
+    /// to `i16x4`.  `M` must be half the number of elements in `self`, which is checked at compile
+    /// time, and is usually inferred from how the output is used.  Together with
+    /// [`widen_high`](Self::widen_high), this matches instructions like `pmovsx*` and
+    /// `punpckh*` on x86 and `sxtl`/`sxtl2` on AArch64.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = i8x8::from_array([-1, 2, -3, 4, -5, 6, -7, 8]);
+    /// let low = x.widen_low();
+    /// assert_eq!(low, i16x4::from_array([-1, 2, -3, 4]));
+    /// ```
+    #[must_use]
+    fn widen_low<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+    where
+        Self::Scalar: Widen;
+
+    /// Converts the second half of the elements to a signed integer type twice as wide, like
+    /// [`widen_low`](Self::widen_low).
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = i8x8::from_array([-1, 2, -3, 4, -5, 6, -7, 8]);
+    /// let high = x.widen_high();
+    /// assert_eq!(high, i16x4::from_array([-5, 6, -7, 8]));
+    /// ```
+    #[must_use]
+    fn widen_high<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+    where
+        Self::Scalar: Widen;
+
+    /// Converts the elements to a signed integer type twice as wide, returning the first and
+    /// second halves like [`widen_low`](Self::widen_low) and [`widen_high`](Self::widen_high).
+    ///
+    /// This is useful when the converted elements don't fit in a single register, such as
+    /// converting `i8x32` to `i16x32` on a target with 256-bit registers.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = i8x8::from_array([-1, 2, -3, 4, -5, 6, -7, 8]);
+    /// let (low, high) = x.widen();
+    /// assert_eq!(low, i16x4::from_array([-1, 2, -3, 4]));
+    /// assert_eq!(high, i16x4::from_array([-5, 6, -7, 8]));
+    /// ```
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    fn widen<const M: usize>(
+        self,
+    ) -> (
+        Simd<<Self::Scalar as Widen>::Output, M>,
+        Simd<<Self::Scalar as Widen>::Output, M>,
+    )
+    where
+        Self::Scalar: Widen;
+
     /// Lanewise saturating add.
     ///
     /// # Examples
@@ -252,6 +363,52 @@ macro_rules! impl_trait {
                 unsafe { core::intrinsics::simd::simd_as(self) }
             }
 
+            #[inline]
+            fn saturating_cast<T: SimdCast>(self) -> Self::Cast<T> {
+                if T::FLOAT {
+                    self.cast()
+                } else {
+                    // Shifting preserves the sign, leaving the bounds of `T` if they're narrower
+                    const VALUE_BITS: u32 = <$ty>::BITS - 1;
+                    let shift = VALUE_BITS.saturating_sub(T::BITS - T::SIGNED as u32);
+                    let min = if T::SIGNED { <$ty>::MIN >> shift } else { 0 };
+                    self.simd_clamp(Simd::splat(min), Simd::splat(<$ty>::MAX >> shift)).cast()
+                }
+            }
+
+            #[inline]
+            fn pack_saturating<T: SimdCast, const M: usize>(self, other: Self) -> Simd<T, M> {
+                self.saturating_cast::<T>().concat(other.saturating_cast::<T>())
+            }
+
+            #[inline]
+            fn widen_low<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+            where
+                Self::Scalar: Widen,
+            {
+                const { assert!(2 * M == N, "output must have half as many elements") };
+                self.extract::<0, M>().cast()
+            }
+
+            #[inline]
+            fn widen_high<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+            where
+                Self::Scalar: Widen,
+            {
+                const { assert!(2 * M == N, "output must have half as many elements") };
+                self.extract::<M, M>().cast()
+            }
+
+            #[inline]
+            fn widen<const M: usize>(
+                self,
+            ) -> (Simd<<Self::Scalar as Widen>::Output, M>, Simd<<Self::Scalar as Widen>::Output, M>)
+            where
+                Self::Scalar: Widen,
+            {
+                (self.widen_low(), self.widen_high())
+            }
+
             #[inline]
             fn saturating_add(self, second: Self) -> Self {
                 // Safety: `self` is a vector
@@ -397,4 +554,4 @@ macro_rules! impl_trait {
     }
 }
 
-impl_trait! { i8 (u8), i16 (u16), i32 (u32), i64 (u64), isize (usize) }
+impl_trait! { i8 (u8), i16 (u16), i32 (u32), i64 (u64), i128 (u128), isize (usize) }
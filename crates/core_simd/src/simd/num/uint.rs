@@ -1,4 +1,4 @@
-use crate::simd::{Simd, SimdCast, SimdElement, cmp::SimdOrd};
+use crate::simd::{Simd, SimdCast, SimdElement, cmp::SimdOrd, num::Widen};
 
 /// Operations on SIMD vectors of unsigned integers.
 pub impl(self) trait SimdUint: Copy {
@@ -15,6 +15,113 @@ pub impl(self) trait SimdUint: Copy {
     #[must_use]
     fn cast<T: SimdCast>(self) -> Self::Cast<T>;
 
+    /// Performs elementwise conversion to another SIMD-valid type, saturating at the limits of
+    /// integer types.
+    ///
+    /// Unlike [`cast`](Self::cast), elements greater than the maximum value of an integer type
+    /// become that maximum.  Conversions to float types are the same as `cast`.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = u16x4::from_array([0, 100, 200, 300]);
+    /// assert_eq!(x.saturating_cast::<u8>(), u8x4::from_array([0, 100, 200, 255]));
+    /// assert_eq!(x.saturating_cast::<i8>(), i8x4::from_array([0, 100, 127, 127]));
+    /// ```
+    #[must_use]
+    fn saturating_cast<T: SimdCast>(self) -> Self::Cast<T>;
+
+    /// Converts the elements of `self` followed by the elements of `other` to another
+    /// SIMD-valid type, saturating like [`saturating_cast`](Self::saturating_cast).
+    ///
+    /// `M` must be twice the number of elements in `self`, which is checked at compile time.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let a = u16x2::from_array([1, 256]);
+    /// let b = u16x2::from_array([255, 65535]);
+    /// let packed: u8x4 = a.pack_saturating(b);
+    /// assert_eq!(packed.to_array(), [1, 255, 255, 255]);
+    /// ```
+    #[must_use]
+    fn pack_saturating<T: SimdCast, const M: usize>(self, other: Self) -> Simd<T, M>;
+
+    /// Converts the first half of the elements to an unsigned integer type twice as wide.
+    ///
+    /// The elements are zero-extended, and the output has half as many elements, so `u8x8` widens
+    /// to `u16x4`.  `M` must be half the number of elements in `self`, which is checked at compile
+    /// time, and is usually inferred from how the output is used.  Together with
+    /// [`widen_high`](Self::widen_high), this matches instructions like `pmovzx*` and
+    /// `punpckh*` on x86 and `uxtl`/`uxtl2` on AArch64.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = u8x8::from_array([1, 2, 3, 4, 200, 201, 202, 255]);
+    /// let low = x.widen_low();
+    /// assert_eq!(low, u16x4::from_array([1, 2, 3, 4]));
+    /// ```
+    #[must_use]
+    fn widen_low<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+    where
+        Self::Scalar: Widen;
+
+    /// Converts the second half of the elements to an unsigned integer type twice as wide, like
+    /// [`widen_low`](Self::widen_low).
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = u8x8::from_array([1, 2, 3, 4, 200, 201, 202, 255]);
+    /// let high = x.widen_high();
+    /// assert_eq!(high, u16x4::from_array([200, 201, 202, 255]));
+    /// ```
+    #[must_use]
+    fn widen_high<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+    where
+        Self::Scalar: Widen;
+
+    /// Converts the elements to an unsigned integer type twice as wide, returning the first and
+    /// second halves like [`widen_low`](Self::widen_low) and [`widen_high`](Self::widen_high).
+    ///
+    /// This is useful when the converted elements don't fit in a single register, such as
+    /// converting `u8x32` to `u16x32` on a target with 256-bit registers.
+    ///
+    /// # Examples
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::prelude::*;
+    /// let x = u8x8::from_array([1, 2, 3, 4, 200, 201, 202, 255]);
+    /// let (low, high) = x.widen();
+    /// assert_eq!(low, u16x4::from_array([1, 2, 3, 4]));
+    /// assert_eq!(high, u16x4::from_array([200, 201, 202, 255]));
+    /// ```
+    #[must_use]
+    #[allow(clippy::type_complexity)]
+    fn widen<const M: usize>(
+        self,
+    ) -> (
+        Simd<<Self::Scalar as Widen>::Output, M>,
+        Simd<<Self::Scalar as Widen>::Output, M>,
+    )
+    where
+        Self::Scalar: Widen;
+
     /// Wrapping negation.
     ///
     /// Like [`u32::wrapping_neg`], all applications of this function will wrap, with the exception
@@ -134,6 +241,49 @@ macro_rules! impl_trait {
                 unsafe { core::intrinsics::simd::simd_as(self) }
             }
 
+            #[inline]
+            fn saturating_cast<T: SimdCast>(self) -> Self::Cast<T> {
+                if T::FLOAT {
+                    self.cast()
+                } else {
+                    let shift = <$ty>::BITS.saturating_sub(T::BITS - T::SIGNED as u32);
+                    self.simd_min(Simd::splat(<$ty>::MAX >> shift)).cast()
+                }
+            }
+
+            #[inline]
+            fn pack_saturating<T: SimdCast, const M: usize>(self, other: Self) -> Simd<T, M> {
+                self.saturating_cast::<T>().concat(other.saturating_cast::<T>())
+            }
+
+            #[inline]
+            fn widen_low<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+            where
+                Self::Scalar: Widen,
+            {
+                const { assert!(2 * M == N, "output must have half as many elements") };
+                self.extract::<0, M>().cast()
+            }
+
+            #[inline]
+            fn widen_high<const M: usize>(self) -> Simd<<Self::Scalar as Widen>::Output, M>
+            where
+                Self::Scalar: Widen,
+            {
+                const { assert!(2 * M == N, "output must have half as many elements") };
+                self.extract::<M, M>().cast()
+            }
+
+            #[inline]
+            fn widen<const M: usize>(
+                self,
+            ) -> (Simd<<Self::Scalar as Widen>::Output, M>, Simd<<Self::Scalar as Widen>::Output, M>)
+            where
+                Self::Scalar: Widen,
+            {
+                (self.widen_low(), self.widen_high())
+            }
+
             #[inline]
             fn wrapping_neg(self) -> Self {
                 use crate::simd::num::SimdInt;
@@ -250,4 +400,4 @@ macro_rules! impl_trait {
     }
 }
 
-impl_trait! { u8 (i8), u16 (i16), u32 (i32), u64 (i64), usize (isize) }
+impl_trait! { u8 (i8), u16 (i16), u32 (i32), u64 (i64), u128 (i128), usize (isize) }
@@ -0,0 +1,49 @@
+use crate::simd::SimdCast;
+
+/// Integer element types with a natural widening, to the integer type twice as wide with the
+/// same signedness.
+///
+/// This gives the element type of the output of [`SimdInt::widen_low`], [`SimdUint::widen_low`],
+/// and the related methods.  For example, `u8` widens to `u16`, so `u8x16` widens to two
+/// `u16x8`.  It is implemented for 8, 16, 32, and 64-bit integers.
+///
+/// [`SimdInt::widen_low`]: crate::simd::num::SimdInt::widen_low
+/// [`SimdUint::widen_low`]: crate::simd::num::SimdUint::widen_low
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{num::Widen, prelude::*};
+/// fn widened_sum<T, const N: usize, const M: usize>(x: Simd<T, N>) -> Simd<T::Output, M>
+/// where
+///     T: Widen,
+///     Simd<T, N>: SimdUint<Scalar = T>,
+///     Simd<T::Output, M>: core::ops::Add<Output = Simd<T::Output, M>>,
+/// {
+///     let (low, high) = x.widen();
+///     low + high
+/// }
+///
+/// let sum = widened_sum(u8x8::from_array([255, 1, 2, 3, 255, 4, 5, 6]));
+/// assert_eq!(sum, u16x4::from_array([510, 5, 7, 9]));
+/// ```
+pub impl(self) trait Widen: SimdCast {
+    /// The element type twice as wide.
+    type Output: SimdCast;
+}
+
+macro_rules! impl_widen {
+    { $($narrow:ty => $wide:ty),* } => {
+        $(
+        impl Widen for $narrow {
+            type Output = $wide;
+        }
+        )*
+    };
+}
+
+impl_widen! { i8 => i16, i16 => i32, i32 => i64, i64 => i128 }
+impl_widen! { u8 => u16, u16 => u32, u32 => u64, u64 => u128 }
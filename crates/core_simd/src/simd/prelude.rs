@@ -14,6 +14,10 @@ pub use super::{
     simd_swizzle,
 };
 
+#[rustfmt::skip]
+#[doc(no_inline)]
+pub use super::{bf16x1, bf16x2, bf16x4, bf16x8, bf16x16, bf16x32, bf16x64};
+
 #[rustfmt::skip]
 #[doc(no_inline)]
 pub use super::{f16x1, f16x2, f16x4, f16x8, f16x16, f16x32, f16x64};
@@ -42,6 +46,10 @@ pub use super::{i32x1, i32x2, i32x4, i32x8, i32x16, i32x32, i32x64};
 #[doc(no_inline)]
 pub use super::{i64x1, i64x2, i64x4, i64x8, i64x16, i64x32, i64x64};
 
+#[rustfmt::skip]
+#[doc(no_inline)]
+pub use super::{i128x1, i128x2, i128x4, i128x8, i128x16, i128x32, i128x64};
+
 #[rustfmt::skip]
 #[doc(no_inline)]
 pub use super::{isizex1, isizex2, isizex4, isizex8, isizex16, isizex32, isizex64};
@@ -62,6 +70,10 @@ pub use super::{u32x1, u32x2, u32x4, u32x8, u32x16, u32x32, u32x64};
 #[doc(no_inline)]
 pub use super::{u64x1, u64x2, u64x4, u64x8, u64x16, u64x32, u64x64};
 
+#[rustfmt::skip]
+#[doc(no_inline)]
+pub use super::{u128x1, u128x2, u128x4, u128x8, u128x16, u128x32, u128x64};
+
 #[rustfmt::skip]
 #[doc(no_inline)]
 pub use super::{usizex1, usizex2, usizex4, usizex8, usizex16, usizex32, usizex64};
@@ -82,6 +94,10 @@ pub use super::{mask32x1, mask32x2, mask32x4, mask32x8, mask32x16, mask32x32, ma
 #[doc(no_inline)]
 pub use super::{mask64x1, mask64x2, mask64x4, mask64x8, mask64x16, mask64x32, mask64x64};
 
+#[rustfmt::skip]
+#[doc(no_inline)]
+pub use super::{mask128x1, mask128x2, mask128x4, mask128x8, mask128x16, mask128x32, mask128x64};
+
 #[rustfmt::skip]
 #[doc(no_inline)]
 pub use super::{masksizex1, masksizex2, masksizex4, masksizex8, masksizex16, masksizex32, masksizex64};
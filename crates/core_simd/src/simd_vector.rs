@@ -0,0 +1,252 @@
+use crate::simd::{Mask, Select, Simd, SimdElement, bf16};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+/// A SIMD vector of any element type and length.
+///
+/// Every [`Simd<T, N>`] implements this trait, which allows writing code that is generic over
+/// the vector type rather than over its element type and length.  Combined with
+/// [`SimdWidth`], the length can be chosen from the size of the element type.
+///
+/// [`Cast`](Self::Cast) names the vector with the same number of elements and another element
+/// type, and [`SameWidth`](Self::SameWidth) names the vector with the same width in bits and
+/// another element type.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{Simd, SimdVector};
+/// fn first_lanes<V: SimdVector>(slice: &[V::Scalar]) -> &[V::Scalar] {
+///     &slice[..V::LANES]
+/// }
+///
+/// assert_eq!(first_lanes::<Simd<u8, 2>>(&[1, 2, 3]), [1, 2]);
+/// ```
+///
+/// Vectors with the same width and a different element type:
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{Simd, SimdVector, SimdWidthOf, u8x16};
+/// fn words<V: SimdVector>(_: V) -> V::SameWidth<u32>
+/// where
+///     u32: SimdWidthOf<V>,
+/// {
+///     V::SameWidth::<u32>::splat(1)
+/// }
+///
+/// assert_eq!(words(u8x16::splat(0)), Simd::from_array([1; 4]));
+/// assert_eq!(<<u8x16 as SimdVector>::SameWidth<f64> as SimdVector>::LANES, 2);
+/// ```
+pub impl(self) trait SimdVector: Copy {
+    /// Scalar type contained by this SIMD vector type.
+    type Scalar: SimdElement;
+
+    /// Mask type used for manipulating this SIMD vector type.
+    type Mask: Copy
+        + Not<Output = Self::Mask>
+        + BitAnd<Output = Self::Mask>
+        + BitOr<Output = Self::Mask>
+        + BitXor<Output = Self::Mask>
+        + BitAndAssign
+        + BitOrAssign
+        + BitXorAssign
+        + Select<Self>;
+
+    /// A SIMD vector with the same number of elements and a different element type.
+    type Cast<U: SimdElement>: SimdVector<Scalar = U>;
+
+    /// A SIMD vector with the same width in bits and a different element type.
+    ///
+    /// See [`SimdWidthOf`] for the supported widths.
+    type SameWidth<U: SimdWidthOf<Self>>: SimdVector<Scalar = U>;
+
+    /// Number of elements in this vector.
+    const LANES: usize;
+
+    /// Size of this vector in bits, not including any padding.
+    const BITS: usize;
+
+    /// Constructs a new SIMD vector with all elements set to the given value.
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// Converts a slice to a SIMD vector containing `slice[..Self::LANES]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than [`LANES`](Self::LANES).
+    #[track_caller]
+    fn from_slice(slice: &[Self::Scalar]) -> Self;
+
+    /// Writes a SIMD vector to the first [`LANES`](Self::LANES) elements of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than [`LANES`](Self::LANES).
+    #[track_caller]
+    fn copy_to_slice(self, slice: &mut [Self::Scalar]);
+}
+
+impl<T, const N: usize> SimdVector for Simd<T, N>
+where
+    T: SimdElement,
+{
+    type Scalar = T;
+    type Mask = Mask<T::Mask, N>;
+    type Cast<U: SimdElement> = Simd<U, N>;
+    type SameWidth<U: SimdWidthOf<Self>> = U::Vector;
+
+    const LANES: usize = N;
+    const BITS: usize = N * size_of::<T>() * 8;
+
+    #[inline]
+    fn splat(value: T) -> Self {
+        Self::splat(value)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn from_slice(slice: &[T]) -> Self {
+        Self::from_slice(slice)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn copy_to_slice(self, slice: &mut [T]) {
+        self.copy_to_slice(slice)
+    }
+}
+
+/// Element types that can fill a SIMD vector of `BITS` bits.
+///
+/// [`Vector`](Self::Vector) has as many elements as fit in `BITS` bits, so vectors of
+/// different element types with the same width can be named without computing their lengths.
+/// Widths of 64, 128, 256, and 512 bits are supported, for every primitive element type that
+/// fits.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{SimdVector, SimdWidth};
+/// // Processes 256 bits at a time, regardless of the element type
+/// fn fill<T: SimdWidth<256>>(slice: &mut [T], value: T) {
+///     let lanes = <T::Vector as SimdVector>::LANES;
+///     let mut chunks = slice.chunks_exact_mut(lanes);
+///     for chunk in &mut chunks {
+///         T::Vector::splat(value).copy_to_slice(chunk);
+///     }
+///     chunks.into_remainder().fill(value);
+/// }
+///
+/// let mut bytes = [0u8; 40];
+/// fill(&mut bytes, 7);
+/// assert_eq!(bytes, [7; 40]);
+///
+/// assert_eq!(<<u16 as SimdWidth<256>>::Vector as SimdVector>::LANES, 16);
+/// assert_eq!(<<f64 as SimdWidth<256>>::Vector as SimdVector>::LANES, 4);
+/// ```
+pub impl(self) trait SimdWidth<const BITS: usize>: SimdElement {
+    /// The SIMD vector of this element type that is `BITS` bits wide.
+    type Vector: SimdVector<Scalar = Self>;
+}
+
+/// Element types that can fill a SIMD vector as wide as `V`.
+///
+/// This is implemented for the element types that implement [`SimdWidth`] for the width of `V`,
+/// which makes [`SimdVector::SameWidth`] usable in code that is generic over the vector type.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{SimdVector, SimdWidthOf, f32x8, u16x16};
+/// fn lanes_of<U: SimdWidthOf<V>, V: SimdVector>() -> usize {
+///     <U::Vector as SimdVector>::LANES
+/// }
+///
+/// assert_eq!(lanes_of::<u8, f32x8>(), 32);
+/// assert_eq!(lanes_of::<f64, u16x16>(), 4);
+/// ```
+pub impl(self) trait SimdWidthOf<V: SimdVector>: SimdElement {
+    /// The SIMD vector of this element type that is as wide as `V`.
+    type Vector: SimdVector<Scalar = Self>;
+}
+
+impl<U, V, const BITS: usize> SimdWidthOf<V> for U
+where
+    U: SimdWidth<BITS>,
+    V: VectorWidth<Width = Width<BITS>>,
+{
+    type Vector = <U as SimdWidth<BITS>>::Vector;
+}
+
+/// The width of a vector in bits, as a type.
+pub(crate) struct Width<const BITS: usize>;
+
+/// Vectors with a width supported by [`SimdWidth`].
+pub(crate) trait VectorWidth: SimdVector {
+    /// The [`Width`] of this vector.
+    type Width;
+}
+
+macro_rules! impl_width {
+    { $bits:literal; ($($ty:ty),*) } => {
+        $(
+        impl SimdWidth<$bits> for $ty {
+            type Vector = Simd<$ty, { $bits / (8 * size_of::<$ty>()) }>;
+        }
+
+        impl VectorWidth for Simd<$ty, { $bits / (8 * size_of::<$ty>()) }> {
+            type Width = Width<$bits>;
+        }
+        )*
+
+        impl<T> SimdWidth<$bits> for *const T
+        where
+            T: core::ptr::Pointee<Metadata = ()>,
+        {
+            type Vector = Simd<*const T, { $bits / usize::BITS as usize }>;
+        }
+
+        impl<T> VectorWidth for Simd<*const T, { $bits / usize::BITS as usize }>
+        where
+            T: core::ptr::Pointee<Metadata = ()>,
+        {
+            type Width = Width<$bits>;
+        }
+
+        impl<T> SimdWidth<$bits> for *mut T
+        where
+            T: core::ptr::Pointee<Metadata = ()>,
+        {
+            type Vector = Simd<*mut T, { $bits / usize::BITS as usize }>;
+        }
+
+        impl<T> VectorWidth for Simd<*mut T, { $bits / usize::BITS as usize }>
+        where
+            T: core::ptr::Pointee<Metadata = ()>,
+        {
+            type Width = Width<$bits>;
+        }
+    };
+    { $($bits:literal),*; $tys:tt } => {
+        $(
+        impl_width! { $bits; $tys }
+        )*
+    }
+}
+
+impl_width! { 64; (u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, bf16, f16, f32, f64) }
+impl_width! {
+    128, 256, 512;
+    (u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bf16, f16, f32, f64)
+}
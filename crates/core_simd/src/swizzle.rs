@@ -42,6 +42,7 @@ use crate::simd::{Mask, MaskElement, Simd, SimdElement};
 /// assert_eq!(r.to_array(), [0, 4]);
 /// ```
 #[allow(unused_macros)]
+#[allow_internal_unstable(const_trait_impl)]
 pub macro simd_swizzle {
     (
         $vector:expr, $index:expr $(,)?
@@ -49,7 +50,7 @@ pub macro simd_swizzle {
         {
             use $crate::simd::Swizzle;
             struct Impl;
-            impl Swizzle<{$index.len()}> for Impl {
+            impl const Swizzle<{$index.len()}> for Impl {
                 const INDEX: [usize; {$index.len()}] = $index;
             }
             Impl::swizzle($vector)
@@ -61,7 +62,7 @@ pub macro simd_swizzle {
         {
             use $crate::simd::Swizzle;
             struct Impl;
-            impl Swizzle<{$index.len()}> for Impl {
+            impl const Swizzle<{$index.len()}> for Impl {
                 const INDEX: [usize; {$index.len()}] = $index;
             }
             Impl::concat_swizzle($first, $second)
@@ -70,7 +71,8 @@ pub macro simd_swizzle {
 }
 
 /// Creates a vector from the elements of another vector.
-pub trait Swizzle<const N: usize> {
+#[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+pub const trait Swizzle<const N: usize> {
     /// Map from the elements of the input vector to the output vector.
     const INDEX: [usize; N];
 
@@ -84,10 +86,10 @@ pub trait Swizzle<const N: usize> {
         T: SimdElement,
     {
         // Safety: `vector` is a vector, and the index is a const vector of u32.
-        unsafe {
+        Simd::from_repr(unsafe {
             core::intrinsics::simd::simd_shuffle(
-                vector,
-                vector,
+                vector.to_repr(),
+                vector.to_repr(),
                 const {
                     let mut output = [0; N];
                     let mut i = 0;
@@ -108,7 +110,7 @@ pub trait Swizzle<const N: usize> {
                     SimdShuffleIdx(output)
                 },
             )
-        }
+        })
     }
 
     /// Creates a new vector from the elements of `first` and `second`.
@@ -122,10 +124,10 @@ pub trait Swizzle<const N: usize> {
         T: SimdElement,
     {
         // Safety: `first` and `second` are vectors, and the index is a const vector of u32.
-        unsafe {
+        Simd::from_repr(unsafe {
             core::intrinsics::simd::simd_shuffle(
-                first,
-                second,
+                first.to_repr(),
+                second.to_repr(),
                 const {
                     let mut output = [0; N];
                     let mut i = 0;
@@ -146,7 +148,7 @@ pub trait Swizzle<const N: usize> {
                     SimdShuffleIdx(output)
                 },
             )
-        }
+        })
     }
 
     /// Creates a new mask from the elements of `mask`.
@@ -186,10 +188,12 @@ where
     /// Reverse the order of the elements in the vector.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn reverse(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn reverse(self) -> Self {
         struct Reverse;
 
-        impl<const N: usize> Swizzle<N> for Reverse {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Swizzle<N> for Reverse {
             const INDEX: [usize; N] = const {
                 let mut index = [0; N];
                 let mut i = 0;
@@ -220,10 +224,12 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_elements_left<const OFFSET: usize>(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn rotate_elements_left<const OFFSET: usize>(self) -> Self {
         struct Rotate<const OFFSET: usize>;
 
-        impl<const OFFSET: usize, const N: usize> Swizzle<N> for Rotate<OFFSET> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const OFFSET: usize, const N: usize> const Swizzle<N> for Rotate<OFFSET> {
             const INDEX: [usize; N] = const {
                 let offset = OFFSET % N;
                 let mut index = [0; N];
@@ -255,10 +261,12 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
         struct Rotate<const OFFSET: usize>;
 
-        impl<const OFFSET: usize, const N: usize> Swizzle<N> for Rotate<OFFSET> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const OFFSET: usize, const N: usize> const Swizzle<N> for Rotate<OFFSET> {
             const INDEX: [usize; N] = const {
                 let offset = N - OFFSET % N;
                 let mut index = [0; N];
@@ -289,10 +297,12 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn shift_elements_left<const OFFSET: usize>(self, padding: T) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn shift_elements_left<const OFFSET: usize>(self, padding: T) -> Self {
         struct Shift<const OFFSET: usize>;
 
-        impl<const OFFSET: usize, const N: usize> Swizzle<N> for Shift<OFFSET> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const OFFSET: usize, const N: usize> const Swizzle<N> for Shift<OFFSET> {
             const INDEX: [usize; N] = const {
                 let mut index = [N; N];
                 let mut i = 0;
@@ -322,10 +332,12 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn shift_elements_right<const OFFSET: usize>(self, padding: T) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn shift_elements_right<const OFFSET: usize>(self, padding: T) -> Self {
         struct Shift<const OFFSET: usize>;
 
-        impl<const OFFSET: usize, const N: usize> Swizzle<N> for Shift<OFFSET> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const OFFSET: usize, const N: usize> const Swizzle<N> for Shift<OFFSET> {
             const INDEX: [usize; N] = const {
                 let mut index = [N; N];
                 let mut i = OFFSET;
@@ -358,7 +370,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn interleave(self, other: Self) -> (Self, Self) {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn interleave(self, other: Self) -> (Self, Self) {
         const fn interleave<const N: usize>(high: bool) -> [usize; N] {
             let mut idx = [0; N];
             let mut i = 0;
@@ -374,11 +387,13 @@ where
         struct Lo;
         struct Hi;
 
-        impl<const N: usize> Swizzle<N> for Lo {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Swizzle<N> for Lo {
             const INDEX: [usize; N] = interleave::<N>(false);
         }
 
-        impl<const N: usize> Swizzle<N> for Hi {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Swizzle<N> for Hi {
             const INDEX: [usize; N] = interleave::<N>(true);
         }
 
@@ -411,7 +426,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn deinterleave(self, other: Self) -> (Self, Self) {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn deinterleave(self, other: Self) -> (Self, Self) {
         const fn deinterleave<const N: usize>(second: bool) -> [usize; N] {
             let mut idx = [0; N];
             let mut i = 0;
@@ -425,11 +441,13 @@ where
         struct Even;
         struct Odd;
 
-        impl<const N: usize> Swizzle<N> for Even {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Swizzle<N> for Even {
             const INDEX: [usize; N] = deinterleave::<N>(false);
         }
 
-        impl<const N: usize> Swizzle<N> for Odd {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize> const Swizzle<N> for Odd {
             const INDEX: [usize; N] = deinterleave::<N>(true);
         }
 
@@ -455,9 +473,11 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn resize<const M: usize>(self, value: T) -> Simd<T, M> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn resize<const M: usize>(self, value: T) -> Simd<T, M> {
         struct Resize<const N: usize>;
-        impl<const N: usize, const M: usize> Swizzle<M> for Resize<N> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize, const M: usize> const Swizzle<M> for Resize<N> {
             const INDEX: [usize; M] = const {
                 let mut index = [0; M];
                 let mut i = 0;
@@ -471,6 +491,27 @@ where
         Resize::<N>::concat_swizzle(self, Simd::splat(value))
     }
 
+    /// Concatenates two vectors.
+    ///
+    /// `M` must be twice `N`.
+    #[inline]
+    pub(crate) fn concat<const M: usize>(self, other: Self) -> Simd<T, M> {
+        const { assert!(M == 2 * N, "output must have twice as many elements") };
+        struct Concat;
+        impl<const M: usize> Swizzle<M> for Concat {
+            const INDEX: [usize; M] = const {
+                let mut index = [0; M];
+                let mut i = 0;
+                while i < M {
+                    index[i] = i;
+                    i += 1;
+                }
+                index
+            };
+        }
+        Concat::concat_swizzle(self, other)
+    }
+
     /// Extract a vector from another vector.
     ///
     /// ```
@@ -483,9 +524,13 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn extract<const START: usize, const LEN: usize>(self) -> Simd<T, LEN> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn extract<const START: usize, const LEN: usize>(self) -> Simd<T, LEN> {
         struct Extract<const N: usize, const START: usize>;
-        impl<const N: usize, const START: usize, const LEN: usize> Swizzle<LEN> for Extract<N, START> {
+        #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+        impl<const N: usize, const START: usize, const LEN: usize> const Swizzle<LEN>
+            for Extract<N, START>
+        {
             const INDEX: [usize; LEN] = const {
                 assert!(START + LEN <= N, "index out of bounds");
                 let mut index = [0; LEN];
@@ -508,7 +553,8 @@ where
     /// Reverse the order of the elements in the mask.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn reverse(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn reverse(self) -> Self {
         // Safety: swizzles are safe for masks
         unsafe { Self::from_simd_unchecked(self.to_simd().reverse()) }
     }
@@ -518,7 +564,8 @@ where
     /// the element previously at index `OFFSET` will become the first element in the slice.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_elements_left<const OFFSET: usize>(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn rotate_elements_left<const OFFSET: usize>(self) -> Self {
         // Safety: swizzles are safe for masks
         unsafe { Self::from_simd_unchecked(self.to_simd().rotate_elements_left::<OFFSET>()) }
     }
@@ -528,7 +575,8 @@ where
     /// the element previously at index `self.len() - OFFSET` will become the first element in the slice.
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn rotate_elements_right<const OFFSET: usize>(self) -> Self {
         // Safety: swizzles are safe for masks
         unsafe { Self::from_simd_unchecked(self.to_simd().rotate_elements_right::<OFFSET>()) }
     }
@@ -537,7 +585,8 @@ where
     /// `padding` from the right.
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original inputs"]
-    pub fn shift_elements_left<const OFFSET: usize>(self, padding: bool) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn shift_elements_left<const OFFSET: usize>(self, padding: bool) -> Self {
         // Safety: swizzles are safe for masks
         unsafe {
             Self::from_simd_unchecked(self.to_simd().shift_elements_left::<OFFSET>(if padding {
@@ -552,7 +601,8 @@ where
     /// `padding` from the left.
     #[inline]
     #[must_use = "method returns a new mask and does not mutate the original inputs"]
-    pub fn shift_elements_right<const OFFSET: usize>(self, padding: bool) -> Self {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn shift_elements_right<const OFFSET: usize>(self, padding: bool) -> Self {
         // Safety: swizzles are safe for masks
         unsafe {
             Self::from_simd_unchecked(self.to_simd().shift_elements_right::<OFFSET>(if padding {
@@ -583,7 +633,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn interleave(self, other: Self) -> (Self, Self) {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn interleave(self, other: Self) -> (Self, Self) {
         let (lo, hi) = self.to_simd().interleave(other.to_simd());
         // Safety: swizzles are safe for masks
         unsafe { (Self::from_simd_unchecked(lo), Self::from_simd_unchecked(hi)) }
@@ -612,7 +663,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn deinterleave(self, other: Self) -> (Self, Self) {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn deinterleave(self, other: Self) -> (Self, Self) {
         let (even, odd) = self.to_simd().deinterleave(other.to_simd());
         // Safety: swizzles are safe for masks
         unsafe {
@@ -639,7 +691,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn resize<const M: usize>(self, value: bool) -> Mask<T, M> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn resize<const M: usize>(self, value: bool) -> Mask<T, M> {
         // Safety: swizzles are safe for masks
         unsafe {
             Mask::<T, M>::from_simd_unchecked(self.to_simd().resize::<M>(if value {
@@ -662,7 +715,8 @@ where
     /// ```
     #[inline]
     #[must_use = "method returns a new vector and does not mutate the original inputs"]
-    pub fn extract<const START: usize, const LEN: usize>(self) -> Mask<T, LEN> {
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn extract<const START: usize, const LEN: usize>(self) -> Mask<T, LEN> {
         // Safety: swizzles are safe for masks
         unsafe { Mask::<T, LEN>::from_simd_unchecked(self.to_simd().extract::<START, LEN>()) }
     }
@@ -1,5 +1,6 @@
+#[allow(unused_imports, reason = "only used on some targets/features")]
+use crate::core_simd::transize::transize;
 use crate::simd::Simd;
-use core::mem;
 
 impl<const N: usize> Simd<u8, N> {
     /// Swizzle a vector of bytes according to the index vector.
@@ -50,27 +51,27 @@ impl<const N: usize> Simd<u8, N> {
                     target_feature = "neon",
                     target_endian = "little"
                 ))]
-                16 => transize(armv7_neon_swizzle_u8x16, self, idxs),
+                16 => transize!(armv7_neon_swizzle_u8x16, self, idxs),
 
                 // WASM SIMD128
                 #[cfg(target_feature = "simd128")]
-                16 => transize(wasm::i8x16_swizzle, self, idxs),
+                16 => transize!(wasm::i8x16_swizzle, self, idxs),
                 #[cfg(target_feature = "simd128")]
-                32 => transize(swizzle_dyn_split::<32, 16>, self, idxs),
+                32 => transize!(swizzle_dyn_split::<32, 16>, self, idxs),
 
                 // LoongArch64
                 #[cfg(all(target_arch = "loongarch64", target_feature = "lsx"))]
-                16 => transize(loong64_lsx_swizzle, self, idxs),
+                16 => transize!(loong64_lsx_swizzle, self, idxs),
                 #[cfg(all(target_arch = "loongarch64", target_feature = "lasx"))]
-                32 => transize(loong64_lasx_swizzle, self, idxs),
+                32 => transize!(loong64_lasx_swizzle, self, idxs),
                 #[cfg(all(target_arch = "loongarch64", target_feature = "lsx"))]
-                32 => transize(swizzle_dyn_split::<32, 16>, self, idxs),
+                32 => transize!(swizzle_dyn_split::<32, 16>, self, idxs),
                 #[cfg(all(target_arch = "loongarch64", target_feature = "lasx"))]
-                64 => transize(swizzle_dyn_split::<64, 32>, self, idxs),
+                64 => transize!(swizzle_dyn_split::<64, 32>, self, idxs),
 
                 // x86, x86-64
                 #[cfg(target_feature = "ssse3")]
-                16 => transize(x86::_mm_shuffle_epi8, self, zeroing_idxs(idxs)),
+                16 => transize!(x86::_mm_shuffle_epi8, self, zeroing_idxs(idxs)),
                 #[cfg(all(target_feature = "avx512vl", target_feature = "avx512vbmi"))]
                 32 => {
                     let swizzler = |bytes, idxs| {
@@ -80,12 +81,12 @@ impl<const N: usize> Simd<u8, N> {
                             x86::_mm256_min_epu8(idxs, Simd::<u8, 32>::splat(N as u8).into());
                         x86::_mm256_permutex2var_epi8(bytes, idxs, x86::_mm256_setzero_si256())
                     };
-                    transize(swizzler, self, idxs)
+                    transize!(swizzler, self, idxs)
                 }
                 #[cfg(target_feature = "avx2")]
-                32 => transize(avx2_pshufb, self, idxs),
+                32 => transize!(avx2_pshufb, self, idxs),
                 #[cfg(target_feature = "ssse3")]
-                32 => transize(swizzle_dyn_split::<32, 16>, self, idxs),
+                32 => transize!(swizzle_dyn_split::<32, 16>, self, idxs),
                 // Notable absence: avx512bw pshufb shuffle
                 #[cfg(all(target_feature = "avx512vl", target_feature = "avx512vbmi"))]
                 64 => {
@@ -96,10 +97,10 @@ impl<const N: usize> Simd<u8, N> {
                             x86::_mm512_min_epu8(idxs, Simd::<u8, 64>::splat(N as u8).into());
                         x86::_mm512_permutex2var_epi8(bytes, idxs, x86::_mm512_setzero_si512())
                     };
-                    transize(swizzler, self, idxs)
+                    transize!(swizzler, self, idxs)
                 }
                 #[cfg(target_feature = "avx2")]
-                64 => transize(swizzle_dyn_split::<64, 32>, self, idxs),
+                64 => transize!(swizzle_dyn_split::<64, 32>, self, idxs),
 
                 // scalar fallback
                 _ => {
@@ -313,24 +314,6 @@ unsafe fn loong64_lasx_swizzle(bytes: Simd<u8, 32>, idxs: Simd<u8, 32>) -> Simd<
     }
 }
 
-/// This sets up a call to an architecture-specific function, and in doing so
-/// it persuades rustc that everything is the correct size. Which it is.
-/// This would not be needed if one could convince Rust that, by matching on N,
-/// N is that value, and thus it would be valid to substitute e.g. 16.
-///
-/// # Safety
-/// The correctness of this function hinges on the sizes agreeing in actuality.
-#[allow(dead_code)]
-#[inline(always)]
-unsafe fn transize<T, const N: usize>(
-    f: unsafe fn(T, T) -> T,
-    a: Simd<u8, N>,
-    b: Simd<u8, N>,
-) -> Simd<u8, N> {
-    // SAFETY: Same obligation to use this function as to use mem::transmute_copy.
-    unsafe { mem::transmute_copy(&f(mem::transmute_copy(&a), mem::transmute_copy(&b))) }
-}
-
 /// Make indices that yield 0 for x86
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[allow(unused)]
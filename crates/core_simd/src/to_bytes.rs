@@ -1,10 +1,11 @@
 use crate::simd::{
-    Simd,
+    Mask, MaskElement, Simd,
     num::{SimdFloat, SimdInt, SimdUint},
 };
+use core::mem;
 
 /// Converts SIMD vectors to vectors of bytes
-pub impl(self) trait ToBytes {
+pub impl(self) trait ToBytes: Copy {
     /// This type, reinterpreted as bytes.
     type Bytes: Copy
         + Unpin
@@ -36,9 +37,126 @@ pub impl(self) trait ToBytes {
 
     /// Creates an integer value from its representation as a byte array in little endian.
     fn from_le_bytes(bytes: Self::Bytes) -> Self;
+
+    /// Writes the memory representation of each vector to a byte slice in native byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    #[inline]
+    #[track_caller]
+    fn copy_to_ne_bytes(vectors: &[Self], bytes: &mut [u8]) {
+        copy_to_bytes(vectors, bytes, Self::to_ne_bytes)
+    }
+
+    /// Writes the memory representation of each vector to a byte slice in big-endian
+    /// (network) byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u16x2, ToBytes};
+    /// let vectors = [u16x2::from_array([0x0102, 0x0304]), u16x2::splat(0xabcd)];
+    /// let mut bytes = [0; 8];
+    /// u16x2::copy_to_be_bytes(&vectors, &mut bytes);
+    /// assert_eq!(bytes, [0x01, 0x02, 0x03, 0x04, 0xab, 0xcd, 0xab, 0xcd]);
+    ///
+    /// let mut decoded = [u16x2::splat(0); 2];
+    /// u16x2::copy_from_be_bytes(&bytes, &mut decoded);
+    /// assert_eq!(decoded, vectors);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn copy_to_be_bytes(vectors: &[Self], bytes: &mut [u8]) {
+        copy_to_bytes(vectors, bytes, Self::to_be_bytes)
+    }
+
+    /// Writes the memory representation of each vector to a byte slice in little-endian byte
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    #[inline]
+    #[track_caller]
+    fn copy_to_le_bytes(vectors: &[Self], bytes: &mut [u8]) {
+        copy_to_bytes(vectors, bytes, Self::to_le_bytes)
+    }
+
+    /// Reads each vector from its memory representation in a byte slice in native byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    #[inline]
+    #[track_caller]
+    fn copy_from_ne_bytes(bytes: &[u8], vectors: &mut [Self]) {
+        copy_from_bytes(bytes, vectors, Self::from_ne_bytes)
+    }
+
+    /// Reads each vector from its memory representation in a byte slice in big-endian
+    /// (network) byte order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    #[inline]
+    #[track_caller]
+    fn copy_from_be_bytes(bytes: &[u8], vectors: &mut [Self]) {
+        copy_from_bytes(bytes, vectors, Self::from_be_bytes)
+    }
+
+    /// Reads each vector from its memory representation in a byte slice in little-endian byte
+    /// order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the vectors.
+    #[inline]
+    #[track_caller]
+    fn copy_from_le_bytes(bytes: &[u8], vectors: &mut [Self]) {
+        copy_from_bytes(bytes, vectors, Self::from_le_bytes)
+    }
+}
+
+#[inline]
+#[track_caller]
+fn copy_to_bytes<V: ToBytes>(vectors: &[V], bytes: &mut [u8], f: impl Fn(V) -> V::Bytes) {
+    let size = mem::size_of::<V::Bytes>();
+    assert!(
+        bytes.len() == vectors.len() * size,
+        "byte slice length must be the total size of the vectors"
+    );
+    for (vector, chunk) in vectors.iter().zip(bytes.chunks_exact_mut(size)) {
+        chunk.copy_from_slice(f(*vector).as_ref());
+    }
+}
+
+#[inline]
+#[track_caller]
+fn copy_from_bytes<V: ToBytes>(bytes: &[u8], vectors: &mut [V], f: impl Fn(V::Bytes) -> V) {
+    let size = mem::size_of::<V::Bytes>();
+    assert!(
+        bytes.len() == vectors.len() * size,
+        "byte slice length must be the total size of the vectors"
+    );
+    for (vector, chunk) in vectors.iter_mut().zip(bytes.chunks_exact(size)) {
+        // Reuse the vector's own bytes as storage, since `V::Bytes` has no constructor
+        let mut buffer = vector.to_ne_bytes();
+        buffer.as_mut().copy_from_slice(chunk);
+        *vector = f(buffer);
+    }
 }
 
 macro_rules! swap_bytes {
+    { f16, $x:expr } => { Simd::from_bits($x.to_bits().swap_bytes()) };
     { f32, $x:expr } => { Simd::from_bits($x.to_bits().swap_bytes()) };
     { f64, $x:expr } => { Simd::from_bits($x.to_bits().swap_bytes()) };
     { $ty:ty, $x:expr } => { $x.swap_bytes() }
@@ -120,6 +238,7 @@ impl_to_bytes! { u8, 1 }
 impl_to_bytes! { u16, 2 }
 impl_to_bytes! { u32, 4 }
 impl_to_bytes! { u64, 8 }
+impl_to_bytes! { u128, 16 }
 #[cfg(target_pointer_width = "32")]
 impl_to_bytes! { usize, 4 }
 #[cfg(target_pointer_width = "64")]
@@ -129,10 +248,119 @@ impl_to_bytes! { i8, 1 }
 impl_to_bytes! { i16, 2 }
 impl_to_bytes! { i32, 4 }
 impl_to_bytes! { i64, 8 }
+impl_to_bytes! { i128, 16 }
 #[cfg(target_pointer_width = "32")]
 impl_to_bytes! { isize, 4 }
 #[cfg(target_pointer_width = "64")]
 impl_to_bytes! { isize, 8 }
 
+impl_to_bytes! { f16, 2 }
 impl_to_bytes! { f32, 4 }
 impl_to_bytes! { f64, 8 }
+
+/// Masks are converted through their integer representation, where 0 represents `false` and
+/// -1 represents `true`.  Every byte of an element is the same, so the byte order doesn't
+/// affect the result.
+///
+/// Converting from bytes sets every element that isn't 0 to `true`, so it never fails.  To
+/// reject elements that aren't 0 or -1, use [`Mask::try_from_bytes`] or
+/// [`Mask::try_copy_from_bytes`].
+impl<T, const N: usize> ToBytes for Mask<T, N>
+where
+    T: MaskElement,
+    Simd<T, N>: ToBytes,
+{
+    type Bytes = <Simd<T, N> as ToBytes>::Bytes;
+
+    #[inline]
+    fn to_ne_bytes(self) -> Self::Bytes {
+        self.to_simd().to_ne_bytes()
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> Self::Bytes {
+        self.to_ne_bytes()
+    }
+
+    #[inline]
+    fn to_le_bytes(self) -> Self::Bytes {
+        self.to_ne_bytes()
+    }
+
+    #[inline]
+    fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+        let value = Simd::from_ne_bytes(bytes);
+        // Safety: `value` is an integer vector, and comparisons return 0 or -1
+        unsafe {
+            let nonzero = core::intrinsics::simd::simd_ne(value, Self::splat(false).to_simd());
+            Self::from_simd_unchecked(nonzero)
+        }
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_ne_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: Self::Bytes) -> Self {
+        Self::from_ne_bytes(bytes)
+    }
+}
+
+impl<T, const N: usize> Mask<T, N>
+where
+    T: MaskElement,
+    Simd<T, N>: ToBytes,
+{
+    /// Creates a mask from its memory representation as a byte array, or returns `None` if
+    /// any element is not 0 or -1.
+    ///
+    /// Valid masks have the same representation in every byte order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{Mask, Simd};
+    /// let bytes = Simd::from_array([0xff, 0xff, 0, 0]);
+    /// assert_eq!(Mask::<i16, 2>::try_from_bytes(bytes), Some(Mask::from_array([true, false])));
+    ///
+    /// let bytes = Simd::from_array([0xff, 0, 0, 0]);
+    /// assert_eq!(Mask::<i16, 2>::try_from_bytes(bytes), None);
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new mask and does not mutate the original value"]
+    pub fn try_from_bytes(bytes: <Self as ToBytes>::Bytes) -> Option<Self> {
+        let value = Simd::from_ne_bytes(bytes);
+        // Safety: `value` was checked to be a valid mask
+        Self::valid(value).then(|| unsafe { Self::from_simd_unchecked(value) })
+    }
+
+    /// Reads each mask from its memory representation in a byte slice, like
+    /// [`ToBytes::copy_from_ne_bytes`], but checks that every element is 0 or -1.
+    ///
+    /// Returns the index of the first invalid mask as an error.  The masks before it are
+    /// written, and the rest are unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the byte slice's length isn't the total size of the masks.
+    #[inline]
+    #[track_caller]
+    pub fn try_copy_from_bytes(bytes: &[u8], masks: &mut [Self]) -> Result<(), usize> {
+        let size = mem::size_of::<<Self as ToBytes>::Bytes>();
+        assert!(
+            bytes.len() == masks.len() * size,
+            "byte slice length must be the total size of the vectors"
+        );
+        for (i, (mask, chunk)) in masks.iter_mut().zip(bytes.chunks_exact(size)).enumerate() {
+            let mut buffer = mask.to_ne_bytes();
+            buffer.as_mut().copy_from_slice(chunk);
+            *mask = Self::try_from_bytes(buffer).ok_or(i)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+/// This sets up a call to an architecture-specific function, and in doing so
+/// it persuades rustc that everything is the correct size. Which it is.
+/// This would not be needed if one could convince Rust that, by matching on N,
+/// N is that value, and thus it would be valid to substitute e.g. 16.
+///
+/// Each argument is transmuted to the function's parameter type, and the result is transmuted
+/// to the caller's vector type.  This is a macro rather than a function so that it works for
+/// functions with any number of parameters.
+///
+/// # Safety
+/// The correctness of this macro hinges on the sizes agreeing in actuality, with the same
+/// obligations as [`mem::transmute_copy`](core::mem::transmute_copy).  It must be used in an
+/// `unsafe` block.
+#[allow(unused_macros)]
+pub(crate) macro transize($f:expr, $($arg:expr),+ $(,)?) {
+    core::mem::transmute_copy(&$f($(core::mem::transmute_copy(&$arg)),+))
+}
@@ -1,7 +1,7 @@
 use core::intrinsics::simd::SimdAlign;
 
 use crate::simd::{
-    Mask, MaskElement,
+    Mask, MaskElement, SimdCast, bf16,
     cmp::SimdPartialOrd,
     num::SimdUint,
     ptr::{SimdConstPtr, SimdMutPtr},
@@ -53,7 +53,7 @@ use crate::simd::{
 /// Thus it is sound to [`transmute`] `Simd<T, N>` to `[T; N]` and should optimize to "zero cost",
 /// but the reverse transmutation may require a copy the compiler cannot simply elide.
 ///
-/// `N` cannot be 0 and may be at most 64. This limit may be increased in the future.
+/// `N` cannot be 0 and may be at most 512. This limit may be increased in the future.
 ///
 /// # ABI "Features"
 /// Due to Rust's safety guarantees, `Simd<T, N>` is currently passed and returned via memory,
@@ -104,7 +104,7 @@ use crate::simd::{
 // avoided, as it will likely become illegal on `#[repr(simd)]` structs in the future. It also
 // causes rustc to emit illegal LLVM IR in some cases.
 #[repr(simd, packed)]
-#[rustc_simd_monomorphize_lane_limit = "64"]
+#[rustc_simd_monomorphize_lane_limit = "512"]
 pub struct Simd<T, const N: usize>([T; N])
 where
     T: SimdElement;
@@ -149,8 +149,10 @@ where
     #[inline]
     #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
     pub const fn splat(value: T) -> Self {
-        // SAFETY: T is a SimdElement, and the item type of Self.
-        unsafe { core::intrinsics::simd::simd_splat(value) }
+        // Safety: `T::Repr` has the same representation as `T`
+        let value: T::Repr = unsafe { core::mem::transmute_copy(&value) };
+        // SAFETY: T::Repr is a SimdElement, and the item type of the vector.
+        Self::from_repr(unsafe { core::intrinsics::simd::simd_splat(value) })
     }
 
     /// Returns an array reference containing the entire SIMD vector.
@@ -256,6 +258,81 @@ where
         }
     }
 
+    /// Constructs a vector by calling a function with the index of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let v = u32x4::from_fn(|i| 1 << i);
+    /// assert_eq!(v.to_array(), [1, 2, 4, 8]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self::from_array(core::array::from_fn(f))
+    }
+
+    /// Returns a vector of the same length as `self`, with the function applied to each
+    /// element in order.
+    ///
+    /// This is a scalar fallback for operations without a vector equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f32x4, u32x4};
+    /// let v = u32x4::from_array([1, 10, 100, 1000]);
+    /// assert_eq!(v.map(u32::ilog10), u32x4::from_array([0, 1, 2, 3]));
+    /// assert_eq!(v.map(|x| x as f32 / 2.0), f32x4::from_array([0.5, 5.0, 50.0, 500.0]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn map<U, F>(self, f: F) -> Simd<U, N>
+    where
+        U: SimdElement,
+        F: FnMut(T) -> U,
+    {
+        Simd::from_array(self.to_array().map(f))
+    }
+
+    /// Returns a vector of the same length as `self`, with the function applied to each pair
+    /// of elements from `self` and `other` in order.
+    ///
+    /// This is a scalar fallback for operations without a vector equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{u32x4, u8x4};
+    /// let a = u32x4::from_array([1, 2, 3, 4]);
+    /// let b = u8x4::from_array([0, 4, 8, 32]);
+    /// assert_eq!(a.zip_with(b, |x, y| x.rotate_left(y.into())), u32x4::from_array([1, 32, 768, 4]));
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn zip_with<U, R, F>(self, other: Simd<U, N>, mut f: F) -> Simd<R, N>
+    where
+        U: SimdElement,
+        R: SimdElement,
+        F: FnMut(T, U) -> R,
+    {
+        let (a, b) = (self.to_array(), other.to_array());
+        Simd::from_array(core::array::from_fn(|i| f(a[i], b[i])))
+    }
+
     /// Converts a slice to a SIMD vector containing `slice[..N]`.
     ///
     /// # Panics
@@ -455,11 +532,14 @@ where
     ) -> Self {
         // SAFETY: The safety of reading elements through `ptr` is ensured by the caller.
         unsafe {
-            core::intrinsics::simd::simd_masked_load::<_, _, _, { SimdAlign::Element }>(
-                enable.to_simd(),
-                ptr,
-                or,
-            )
+            Self::from_repr(core::intrinsics::simd::simd_masked_load::<
+                _,
+                _,
+                _,
+                { SimdAlign::Element },
+            >(
+                enable.to_simd(), ptr.cast::<T::Repr>(), or.to_repr()
+            ))
         }
     }
 
@@ -638,7 +718,13 @@ where
         or: Self,
     ) -> Self {
         // Safety: The caller is responsible for upholding all invariants
-        unsafe { core::intrinsics::simd::simd_gather(or, source, enable.to_simd()) }
+        unsafe {
+            Self::from_repr(core::intrinsics::simd::simd_gather(
+                or.to_repr(),
+                source.cast::<T::Repr>(),
+                enable.to_simd(),
+            ))
+        }
     }
 
     /// Conditionally write contiguous elements to `slice`. The `enable` mask controls
@@ -712,8 +798,8 @@ where
         unsafe {
             core::intrinsics::simd::simd_masked_store::<_, _, _, { SimdAlign::Element }>(
                 enable.to_simd(),
-                ptr,
-                self,
+                ptr.cast::<T::Repr>(),
+                self.to_repr(),
             )
         }
     }
@@ -874,7 +960,13 @@ where
     #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
     pub unsafe fn scatter_select_ptr(self, dest: Simd<*mut T, N>, enable: Mask<isize, N>) {
         // Safety: The caller is responsible for upholding all invariants
-        unsafe { core::intrinsics::simd::simd_scatter(self, dest, enable.to_simd()) }
+        unsafe {
+            core::intrinsics::simd::simd_scatter(
+                self.to_repr(),
+                dest.cast::<T::Repr>(),
+                enable.to_simd(),
+            )
+        }
     }
 }
 
@@ -900,16 +992,43 @@ where
     }
 }
 
+impl<T, const N: usize> Simd<T, N>
+where
+    T: SimdElement,
+{
+    /// Converts to the primitive element type with the same representation, which is
+    /// required by intrinsics that inspect the element type.
+    #[inline]
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub(crate) const fn to_repr(self) -> Simd<T::Repr, N> {
+        // Safety: `T::Repr` has the same representation as `T`
+        unsafe { core::mem::transmute_copy(&self) }
+    }
+
+    /// Converts from the primitive element type with the same representation.
+    #[inline]
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub(crate) const fn from_repr(repr: Simd<T::Repr, N>) -> Self {
+        // Safety: `T::Repr` has the same representation as `T`
+        unsafe { core::mem::transmute_copy(&repr) }
+    }
+}
+
 impl<T, const N: usize> PartialEq for Simd<T, N>
 where
     T: SimdElement + PartialEq,
 {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
+        // Newtypes may not compare their representations
+        if T::TRANSPARENT {
+            return self.as_array() == other.as_array();
+        }
+
         // Safety: All SIMD vectors are SimdPartialEq, and the comparison produces a valid mask.
         let mask = unsafe {
             let tfvec: Simd<<T as SimdElement>::Mask, N> =
-                core::intrinsics::simd::simd_eq(*self, *other);
+                core::intrinsics::simd::simd_eq(self.to_repr(), other.to_repr());
             Mask::from_simd_unchecked(tfvec)
         };
 
@@ -920,10 +1039,14 @@ where
     #[allow(clippy::partialeq_ne_impl)]
     #[inline]
     fn ne(&self, other: &Self) -> bool {
+        if T::TRANSPARENT {
+            return self.as_array() != other.as_array();
+        }
+
         // Safety: All SIMD vectors are SimdPartialEq, and the comparison produces a valid mask.
         let mask = unsafe {
             let tfvec: Simd<<T as SimdElement>::Mask, N> =
-                core::intrinsics::simd::simd_ne(*self, *other);
+                core::intrinsics::simd::simd_ne(self.to_repr(), other.to_repr());
             Mask::from_simd_unchecked(tfvec)
         };
 
@@ -1069,6 +1192,138 @@ where
 pub impl(self) unsafe trait SimdElement: Copy {
     /// The mask element type corresponding to this element type.
     type Mask: MaskElement;
+
+    /// The primitive element type with the same representation, which the compiler can compare.
+    #[doc(hidden)]
+    type Repr: SimdElement<Mask = Self::Mask> = Self;
+
+    /// Whether this is a newtype, whose [`PartialEq`] may differ from comparing representations.
+    #[doc(hidden)]
+    const TRANSPARENT: bool = false;
+}
+
+/// Marker trait for `#[repr(transparent)]` newtypes that may be used as SIMD vector elements.
+///
+/// Implementing this trait makes the newtype a [`SimdElement`] with the same representation as
+/// its inner type.  Vectors of the newtype can be converted to and from vectors of the inner
+/// type with [`Simd::from_inner`] and [`Simd::into_inner`], which compile to nothing.
+///
+/// Comparing vectors of the newtype with [`PartialEq`] uses the newtype's own [`PartialEq`] for
+/// each element.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # #[cfg(feature = "as_crate")] use core_simd::simd;
+/// # #[cfg(not(feature = "as_crate"))] use core::simd;
+/// # use simd::{Simd, TransparentElement};
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// #[repr(transparent)]
+/// struct Meters(f32);
+///
+/// // Safety: `Meters` is a transparent wrapper around `f32`
+/// unsafe impl TransparentElement for Meters {
+///     type Inner = f32;
+/// }
+///
+/// let distances = Simd::from_array([Meters(1.0), Meters(2.0), Meters(3.0), Meters(4.0)]);
+/// let doubled = Simd::<Meters, 4>::from_inner(distances.into_inner() * Simd::splat(2.0));
+/// assert_eq!(doubled.to_array(), [Meters(2.0), Meters(4.0), Meters(6.0), Meters(8.0)]);
+/// ```
+///
+/// # Safety
+/// `Self` must be `#[repr(transparent)]` around `Inner`, and every value of `Inner` must be a
+/// valid value of `Self`.
+pub unsafe trait TransparentElement: Copy {
+    /// The element type wrapped by this type.
+    type Inner: SimdElement;
+}
+
+// Safety: the newtype has the same representation as its inner element type
+unsafe impl<T> SimdElement for T
+where
+    T: TransparentElement,
+{
+    type Mask = <T::Inner as SimdElement>::Mask;
+    type Repr = <T::Inner as SimdElement>::Repr;
+    const TRANSPARENT: bool = true;
+}
+
+impl<T, const N: usize> Simd<T, N>
+where
+    T: TransparentElement,
+{
+    /// Converts a vector of the inner element type to a vector of the newtype.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn from_inner(inner: Simd<T::Inner, N>) -> Self {
+        // Safety: the newtype has the same representation as its inner element type
+        unsafe { core::mem::transmute_copy(&inner) }
+    }
+
+    /// Converts a vector of the newtype to a vector of the inner element type.
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    pub fn into_inner(self) -> Simd<T::Inner, N> {
+        // Safety: the newtype has the same representation as its inner element type
+        unsafe { core::mem::transmute_copy(&self) }
+    }
+}
+
+impl<T, const N: usize> Simd<T, N>
+where
+    T: SimdCast,
+{
+    /// Reinterprets the bits of this vector as a vector with a different element type and
+    /// number of elements.
+    ///
+    /// Both vectors must have the same total size, which is checked at compile time.  The
+    /// elements are reinterpreted in memory order, so the result depends on the endianness of
+    /// the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::{f64x2, u8x16, u32x4};
+    /// let bytes = u32x4::splat(0x01010101).bitcast::<u8, 16>();
+    /// assert_eq!(bytes, u8x16::splat(1));
+    ///
+    /// let floats = f64x2::from_array([1.0, -0.0]);
+    /// let words = floats.bitcast::<u32, 4>();
+    /// assert_eq!(words.bitcast::<f64, 2>(), floats);
+    /// ```
+    ///
+    /// Vectors with different sizes can't be bitcast:
+    ///
+    /// ```compile_fail
+    /// # #![feature(portable_simd)]
+    /// # #[cfg(feature = "as_crate")] use core_simd::simd;
+    /// # #[cfg(not(feature = "as_crate"))] use core::simd;
+    /// # use simd::u32x4;
+    /// let x = u32x4::splat(0).bitcast::<u64, 4>();
+    /// ```
+    #[inline]
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    #[rustc_const_unstable(feature = "portable_simd", issue = "86656")]
+    pub const fn bitcast<U, const M: usize>(self) -> Simd<U, M>
+    where
+        U: SimdCast,
+    {
+        const {
+            assert!(
+                N * size_of::<T>() == M * size_of::<U>(),
+                "vectors must have the same size"
+            )
+        };
+        // Vectors may contain padding, so reinterpret arrays instead
+        // Safety: every bit pattern is valid for primitive numbers, and the arrays have the
+        // same size
+        Simd::from_array(unsafe { core::mem::transmute_copy(&self.to_array()) })
+    }
 }
 
 // Safety: u8 is a valid SIMD element type, and is supported by this API
@@ -1091,6 +1346,11 @@ unsafe impl SimdElement for u64 {
     type Mask = i64;
 }
 
+// Safety: u128 is a valid SIMD element type, and is supported by this API
+unsafe impl SimdElement for u128 {
+    type Mask = i128;
+}
+
 // Safety: usize is a valid SIMD element type, and is supported by this API
 unsafe impl SimdElement for usize {
     type Mask = isize;
@@ -1116,11 +1376,22 @@ unsafe impl SimdElement for i64 {
     type Mask = i64;
 }
 
+// Safety: i128 is a valid SIMD element type, and is supported by this API
+unsafe impl SimdElement for i128 {
+    type Mask = i128;
+}
+
 // Safety: isize is a valid SIMD element type, and is supported by this API
 unsafe impl SimdElement for isize {
     type Mask = isize;
 }
 
+// Safety: bf16 is a transparent wrapper around u16, and is supported by this API
+unsafe impl SimdElement for bf16 {
+    type Mask = i16;
+    type Repr = u16;
+}
+
 // Safety: f16 is a valid SIMD element type, and is supported by this API
 unsafe impl SimdElement for f16 {
     type Mask = i16;
@@ -0,0 +1,143 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::{Mask, Select, Simd, SimdBf16, bf16, num::SimdFloat};
+
+/// Rounds to the nearest `bf16`, by comparing the distance to the candidates above and below.
+fn reference(x: f32) -> f32 {
+    if x.is_nan() || x.is_infinite() {
+        return x;
+    }
+    let below = f32::from_bits(x.to_bits() & 0xffff_0000) as f64;
+    let exponent = ((x.to_bits() >> 23) & 0xff).max(1) as i32;
+    let above = below + 2f64.powi(exponent - 127 - 7).copysign(below);
+    let to_below = (x as f64 - below).abs();
+    let to_above = (above - x as f64).abs();
+    if to_below < to_above || (to_below == to_above && x.to_bits() & 0x1_0000 == 0) {
+        below as f32
+    } else {
+        above as f32
+    }
+}
+
+test_helpers::test_lanes! {
+    fn from_f32<const LANES: usize>() {
+        test_helpers::test_1(&|x: [f32; LANES]| {
+            let result = Simd::<bf16, LANES>::from_f32(Simd::from_array(x)).to_f32();
+            for i in 0..LANES {
+                let expected = reference(x[i]);
+                if expected.is_nan() {
+                    assert!(result[i].is_nan());
+                    assert!(bf16::from_f32(x[i]).to_f32().is_nan());
+                } else if expected.is_subnormal() && result[i] == 0.0 {
+                    // hardware conversions may flush subnormals
+                    assert_eq!(result[i].is_sign_negative(), expected.is_sign_negative());
+                } else {
+                    assert_eq!(result[i].to_bits(), expected.to_bits(), "converting {}", x[i]);
+                }
+                if !expected.is_nan() {
+                    assert_eq!(bf16::from_f32(x[i]).to_f32().to_bits(), expected.to_bits());
+                }
+            }
+            Ok(())
+        });
+    }
+
+    fn from_f32_matches_portable<const LANES: usize>() {
+        // The hardware conversions must round exactly like the portable one
+        test_helpers::test_1(&|x: [f32; LANES]| {
+            let x = x.map(|x| if x.is_normal() { x } else { 1.0 });
+            let result = Simd::<bf16, LANES>::from_f32(Simd::from_array(x)).to_bits();
+            assert_eq!(result.to_array(), x.map(|x| bf16::from_f32(x).to_bits()));
+            Ok(())
+        });
+    }
+
+    fn round_trip<const LANES: usize>() {
+        test_helpers::test_1(&|bits: [u16; LANES]| {
+            let x = Simd::<bf16, LANES>::from_bits(Simd::from_array(bits));
+            assert_eq!(x.to_bits().to_array(), bits);
+            let wide = x.to_f32();
+            for i in 0..LANES {
+                assert_eq!(wide[i].to_bits(), (bits[i] as u32) << 16);
+                assert_eq!(bf16::from_bits(bits[i]).to_f32().to_bits(), wide[i].to_bits());
+            }
+            let narrow = Simd::<bf16, LANES>::from_f32(wide).to_bits();
+            for i in 0..LANES {
+                if wide[i].is_nan() {
+                    assert_eq!(narrow[i], bits[i] | 0x0040);
+                } else if !wide[i].is_subnormal() {
+                    assert_eq!(narrow[i], bits[i]);
+                }
+            }
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn nan_and_overflow() {
+    let x = Simd::from_array([f32::NAN, -f32::NAN, f32::MAX, -f32::MAX]);
+    let y = Simd::<bf16, 4>::from_f32(x).to_f32();
+    assert!(y[0].is_nan() && y[1].is_nan());
+    assert_eq!(y.to_array()[2..], [f32::INFINITY, f32::NEG_INFINITY]);
+    assert!(
+        Simd::<f32, 4>::from(Simd::<bf16, 4>::from_f32(x))
+            .is_nan()
+            .any()
+    );
+}
+
+fn bits<const N: usize>(x: Simd<bf16, N>) -> [u16; N] {
+    x.to_bits().to_array()
+}
+
+#[test]
+fn gather_scatter() {
+    let values = [1.0, 2.0, 3.0].map(bf16::from_f32);
+    let idxs = Simd::from_array([0, 2, 1, 9]);
+    let or = Simd::splat(bf16::from_f32(-1.0));
+
+    let x = Simd::<bf16, 4>::gather_or_default(&values, idxs);
+    assert_eq!(bits(x), [0x3f80, 0x4040, 0x4000, 0]);
+    let x = Simd::gather_or(&values, idxs, or);
+    assert_eq!(bits(x), [0x3f80, 0x4040, 0x4000, 0xbf80]);
+    let enable = Mask::from_array([true, false, true, true]);
+    let x = Simd::gather_select(&values, enable, idxs, or);
+    assert_eq!(bits(x), [0x3f80, 0xbf80, 0x4000, 0xbf80]);
+
+    let mut dest = [bf16::from_f32(0.0); 3];
+    Simd::from_f32(Simd::from_array([4.0, 5.0, 6.0, 7.0])).scatter(&mut dest, idxs);
+    assert_eq!(dest.map(bf16::to_f32), [4.0, 6.0, 5.0]);
+    let enable = Mask::from_array([false, true, true, true]);
+    Simd::<bf16, 4>::splat(bf16::from_f32(8.0)).scatter_select(&mut dest, enable, idxs);
+    assert_eq!(dest.map(bf16::to_f32), [4.0, 8.0, 8.0]);
+}
+
+#[test]
+fn masked_load_store() {
+    let values = [1.0, 2.0, 3.0].map(bf16::from_f32);
+    let or = Simd::splat(bf16::from_f32(-1.0));
+
+    let x = Simd::<bf16, 4>::load_or_default(&values[..2]);
+    assert_eq!(bits(x), [0x3f80, 0x4000, 0, 0]);
+    let x = Simd::<bf16, 4>::load_or(&values, or);
+    assert_eq!(bits(x), [0x3f80, 0x4000, 0x4040, 0xbf80]);
+    let enable = Mask::from_array([false, true, true, true]);
+    let x = Simd::<bf16, 4>::load_select_or_default(&values, enable);
+    assert_eq!(bits(x), [0, 0x4000, 0x4040, 0]);
+    let x = Simd::<bf16, 4>::load_select(&values, enable, or);
+    assert_eq!(bits(x), [0xbf80, 0x4000, 0x4040, 0xbf80]);
+
+    let mut dest = [bf16::from_f32(0.0); 3];
+    Simd::<bf16, 4>::splat(bf16::from_f32(4.0)).store_select(&mut dest, enable);
+    assert_eq!(dest.map(bf16::to_f32), [0.0, 4.0, 4.0]);
+}
+
+#[test]
+fn select() {
+    let a = Simd::<bf16, 4>::splat(bf16::from_f32(1.0));
+    let b = Simd::<bf16, 4>::splat(bf16::from_f32(2.0));
+    let mask = Mask::<i16, 4>::from_array([true, false, false, true]);
+    assert_eq!(bits(mask.select(a, b)), [0x3f80, 0x4000, 0x4000, 0x3f80]);
+    assert_eq!(bits(0b0110.select(a, b)), [0x4000, 0x3f80, 0x3f80, 0x4000]);
+}
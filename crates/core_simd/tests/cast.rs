@@ -34,5 +34,178 @@ cast_types!(i32, u32, i8, u8, f32, f64);
 cast_types!(u32, i32, i8, u8, f32, f64);
 cast_types!(i64, u64, i8, u8, isize, usize, f32, f64);
 cast_types!(u64, i64, i8, u8, isize, usize, f32, f64);
+cast_types!(i128, u128, i8, u8, i64, u64, f32, f64);
+cast_types!(u128, i128, i8, u8, i64, u64, f32, f64);
 cast_types!(isize, usize, i8, u8, f32, f64);
 cast_types!(usize, isize, i8, u8, f32, f64);
+
+mod bitcast {
+    use core_simd::simd::{Simd, f32x4, i64x2, u8x16, u16x8, u32x4};
+
+    #[test]
+    fn roundtrip() {
+        test_helpers::test_1(&|x: [u8; 16]| {
+            let bytes = u8x16::from_array(x);
+            let words = bytes.bitcast::<u32, 4>();
+            for i in 0..4 {
+                let expected = u32::from_ne_bytes(x[4 * i..4 * i + 4].try_into().unwrap());
+                proptest::prop_assert_eq!(words[i], expected);
+            }
+            proptest::prop_assert_eq!(words.bitcast::<u8, 16>(), bytes);
+            proptest::prop_assert_eq!(bytes.bitcast::<i64, 2>().bitcast::<u8, 16>(), bytes);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn floats() {
+        let x = f32x4::from_array([1.0, -0.0, f32::INFINITY, 0.5]);
+        assert_eq!(
+            x.bitcast::<u32, 4>(),
+            u32x4::from_array(x.to_array().map(f32::to_bits))
+        );
+        assert_eq!(i64x2::splat(-1).bitcast::<u16, 8>(), u16x8::splat(u16::MAX));
+    }
+
+    #[test]
+    fn non_power_of_two() {
+        let x = Simd::<u8, 6>::from_array([1, 0, 2, 0, 3, 0]);
+        let y = x.bitcast::<u16, 3>();
+        assert_eq!(y.bitcast::<u8, 6>(), x);
+        assert_eq!(
+            y.to_array(),
+            [[1, 0], [2, 0], [3, 0]].map(u16::from_ne_bytes)
+        );
+    }
+}
+
+mod saturating {
+    use core_simd::simd::prelude::*;
+
+    macro_rules! test_saturating {
+        { $($from:ident => $($to:ident),*;)* } => {
+            $(
+            mod $from {
+                use super::*;
+                $(
+                #[test]
+                fn $to() {
+                    test_helpers::test_1(&|x: [$from; 8]| {
+                        let expected = x.map(|x| {
+                            let saturated = if x < 0 as $from { <$to>::MIN } else { <$to>::MAX };
+                            <$to>::try_from(x).unwrap_or(saturated)
+                        });
+                        proptest::prop_assert_eq!(
+                            Simd::<$from, 8>::from_array(x).saturating_cast::<$to>().to_array(),
+                            expected
+                        );
+                        Ok(())
+                    });
+                }
+                )*
+            }
+            )*
+        }
+    }
+
+    test_saturating! {
+        i8 => u8, i16, u64;
+        i16 => i8, u8, u16, i32;
+        i32 => i8, u16, u32, i64;
+        i64 => i32, u32, u64, i128;
+        u8 => i8, u16;
+        u16 => i8, u8, i16, u32;
+        u32 => i16, i32, u64;
+        u64 => i8, i64, u128;
+    }
+
+    #[test]
+    fn floats() {
+        let x = i32x4::from_array([i32::MIN, -1, 0, i32::MAX]);
+        assert_eq!(x.saturating_cast::<f32>(), x.cast::<f32>());
+        let x = u16x4::from_array([0, 1, 2, u16::MAX]);
+        assert_eq!(x.saturating_cast::<f64>(), x.cast::<f64>());
+    }
+
+    #[test]
+    fn pack() {
+        let a = i16x8::from_array([-300, -1, 0, 1, 127, 128, 255, 256]);
+        let b = i16x8::from_array([i16::MIN, 2, 3, 4, 5, 6, 7, i16::MAX]);
+        let packed: u8x16 = a.pack_saturating(b);
+        assert_eq!(
+            packed.to_array(),
+            [0, 0, 0, 1, 127, 128, 255, 255, 0, 2, 3, 4, 5, 6, 7, 255]
+        );
+        let packed: i8x16 = a.pack_saturating(b);
+        assert_eq!(
+            packed.to_array(),
+            [
+                -128, -1, 0, 1, 127, 127, 127, 127, -128, 2, 3, 4, 5, 6, 7, 127
+            ]
+        );
+        let packed: Simd<u16, 6> = u32x4::from_array([0, 65535, 65536, 7])
+            .resize::<3>(0)
+            .pack_saturating(Simd::splat(u32::MAX));
+        assert_eq!(packed.to_array(), [0, 65535, 65535, 65535, 65535, 65535]);
+    }
+}
+
+mod widen {
+    use core_simd::simd::prelude::*;
+
+    #[test]
+    fn halves() {
+        test_helpers::test_1(&|x: [u8; 32]| {
+            let v = u8x32::from_array(x);
+            let (low, high): (u16x16, u16x16) = v.widen();
+            proptest::prop_assert_eq!(low, v.widen_low());
+            proptest::prop_assert_eq!(high, v.widen_high());
+            for i in 0..16 {
+                proptest::prop_assert_eq!(low[i], u16::from(x[i]));
+                proptest::prop_assert_eq!(high[i], u16::from(x[i + 16]));
+            }
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn sign_extends() {
+        test_helpers::test_1(&|x: [i16; 8]| {
+            let (low, high): (i32x4, i32x4) = i16x8::from_array(x).widen();
+            proptest::prop_assert_eq!(low.to_array(), [x[0], x[1], x[2], x[3]].map(i32::from));
+            proptest::prop_assert_eq!(high.to_array(), [x[4], x[5], x[6], x[7]].map(i32::from));
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn inferred() {
+        // The element type follows from the input, and the length from how the output is used
+        let x = u32x8::from_array([1, 2, 3, u32::MAX, 5, 6, 7, 8]);
+        let sum = x.widen_low() + x.widen_high();
+        assert_eq!(sum, u64x4::from_array([6, 8, 10, 4294967303]));
+        assert_eq!(i64x4::splat(-1).widen().0, Simd::<i128, 2>::splat(-1));
+    }
+
+    #[test]
+    fn non_power_of_two() {
+        let x = Simd::<i8, 6>::from_array([-1, 2, -3, 4, -5, 6]);
+        let (low, high) = x.widen();
+        assert_eq!(low, Simd::<i16, 3>::from_array([-1, 2, -3]));
+        assert_eq!(high, Simd::<i16, 3>::from_array([4, -5, 6]));
+    }
+
+    #[test]
+    fn max_lanes() {
+        let x = Simd::<u8, 512>::from_array(core::array::from_fn(|i| i as u8));
+        let (low, high) = x.widen();
+        assert_eq!(
+            low,
+            Simd::<u16, 256>::from_array(core::array::from_fn(|i| i as u16))
+        );
+        assert_eq!(high, low);
+
+        let x = Simd::<i64, 130>::splat(i64::MIN);
+        assert_eq!(x.widen_high(), Simd::<i128, 65>::splat(i64::MIN.into()));
+    }
+}
@@ -0,0 +1,163 @@
+#![feature(const_ops, const_trait_impl, portable_simd)]
+use core_simd::simd::{
+    Mask, Select, Simd,
+    cmp::{SimdOrd, SimdPartialEq, SimdPartialOrd},
+    f32x4, i32x4, mask32x4,
+    num::{RoundingMode, SimdFloat},
+    simd_swizzle, u8x8,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn arithmetic() {
+    const A: i32x4 = Simd::from_array([1, -2, 3, -4]);
+    const B: i32x4 = Simd::splat(3);
+    const SUM: i32x4 = A + B;
+    const PRODUCT: i32x4 = A * B - B;
+    const QUOTIENT: i32x4 = A / B;
+    const SHIFTED: i32x4 = (A << 2) >> B;
+    const NOT: i32x4 = !-A;
+    const ASSIGNED: i32x4 = {
+        let mut x = A;
+        x += B;
+        x *= &B;
+        x
+    };
+    assert_eq!(SUM.to_array(), [4, 1, 6, -1]);
+    assert_eq!(PRODUCT.to_array(), [0, -9, 6, -15]);
+    assert_eq!(QUOTIENT.to_array(), [0, 0, 1, -1]);
+    assert_eq!(SHIFTED.to_array(), [0, -1, 1, -2]);
+    assert_eq!(NOT.to_array(), [0, -3, 2, -5]);
+    assert_eq!(ASSIGNED.to_array(), [12, 3, 18, -3]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn comparisons() {
+    const A: i32x4 = Simd::from_array([1, -2, 3, -4]);
+    const B: i32x4 = Simd::from_array([1, 2, -3, 4]);
+    const LT: mask32x4 = A.simd_lt(B);
+    const EQ: mask32x4 = A.simd_eq(B);
+    const MAX: i32x4 = A.simd_max(B);
+    const CLAMPED: i32x4 = A.simd_clamp(Simd::splat(-1), Simd::splat(2));
+    const SELECTED: i32x4 = (LT | EQ).select(A, B);
+    const ANY: bool = (LT & !EQ).any();
+    const ALL: bool = Mask::<i32, 4>::splat(true).all();
+    assert_eq!(LT.to_array(), [false, true, false, true]);
+    assert_eq!(EQ.to_array(), [true, false, false, false]);
+    assert_eq!(MAX.to_array(), [1, 2, 3, 4]);
+    assert_eq!(CLAMPED.to_array(), [1, -1, 2, -1]);
+    assert_eq!(SELECTED.to_array(), [1, -2, -3, -4]);
+    const { assert!(ANY && ALL) };
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn float() {
+    const X: f32x4 = Simd::from_array([1.5, -0.25, 4.0, f32::NAN]);
+    const ABS: f32x4 = X.abs();
+    const NAN: mask32x4 = X.is_nan();
+    const FMA: f32x4 = X.mul_add(Simd::splat(2.0), Simd::splat(1.0));
+    const SUM: f32 = Simd::from_array([1.0f32, 2.0, 3.0, 4.0]).reduce_sum();
+    const MAX: f32 = X.reduce_max();
+    const LDEXP: f32x4 = X.ldexp(Simd::splat(2));
+    assert_eq!(ABS.to_array()[..3], [1.5, 0.25, 4.0]);
+    assert_eq!(NAN.to_array(), [false, false, false, true]);
+    assert_eq!(FMA.to_array()[..3], [4.0, 0.5, 9.0]);
+    assert_eq!(SUM, 10.0);
+    assert_eq!(MAX, 4.0);
+    assert_eq!(LDEXP.to_array()[..3], [6.0, -1.0, 16.0]);
+}
+
+macro_rules! test_sqrt {
+    { $name:ident, $ty:ident } => {
+        #[test]
+        #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+        fn $name() {
+            // Special values followed by pseudorandom bit patterns
+            const X: Simd<$ty, 64> = {
+                let mut x = [0.0; 64];
+                let special = [
+                    0.0, -0.0, 1.0, 2.0, 0.5, 3.0, <$ty>::MAX, <$ty>::MIN_POSITIVE,
+                    <$ty>::from_bits(1), <$ty>::from_bits(3), 1.0 + <$ty>::EPSILON,
+                    1.0 - <$ty>::EPSILON / 2.0, <$ty>::INFINITY, -1.0, <$ty>::NAN,
+                ];
+                let mut state: u64 = 0x2545_f491;
+                let mut i = 0;
+                while i < 64 {
+                    state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+                    x[i] = if i < special.len() {
+                        special[i]
+                    } else {
+                        // The high bits, without the sign bit
+                        <$ty>::from_bits((state >> (65 - 8 * size_of::<$ty>())) as _)
+                    };
+                    i += 1;
+                }
+                Simd::from_array(x)
+            };
+            const SQRT: Simd<$ty, 64> = X.sqrt();
+            const RSQRT: Simd<$ty, 64> = X.rsqrt_estimate();
+            const RECIP: Simd<$ty, 64> = X.recip_fast();
+
+            // NaN bits aren't specified, so only compare other values exactly
+            let check = |op: &str, got: Simd<$ty, 64>, expected: Simd<$ty, 64>| {
+                for i in 0..64 {
+                    if expected[i].is_nan() {
+                        assert!(got[i].is_nan(), "{op}({:?})", X[i]);
+                    } else {
+                        assert_eq!(got[i].to_bits(), expected[i].to_bits(), "{op}({:?})", X[i]);
+                    }
+                }
+            };
+            let sqrt = core::hint::black_box(X).sqrt();
+            check("sqrt", SQRT, sqrt);
+
+            // Const evaluation produces exact results instead of estimates
+            check("rsqrt_estimate", RSQRT, Simd::splat(1.0) / sqrt);
+            check("recip_fast", RECIP, Simd::splat(1.0) / X);
+        }
+    }
+}
+
+test_sqrt! { sqrt_f32, f32 }
+test_sqrt! { sqrt_f64, f64 }
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn rounding() {
+    const X: f32x4 = Simd::from_array([2.5, -2.5, 1e10, f32::NAN]);
+    const NEAREST: i32x4 = X.to_int_round(RoundingMode::NearestEven);
+    const FLOOR: (Simd<i8, 4>, mask32x4) = X.to_int_round_checked(RoundingMode::Floor);
+    assert_eq!(NEAREST.to_array(), [2, -2, i32::MAX, 0]);
+    assert_eq!(FLOOR.0.to_array(), [2, -3, i8::MAX, 0]);
+    assert_eq!(FLOOR.1.to_array(), [false, false, true, true]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn swizzles() {
+    const A: u8x8 = Simd::from_array([0, 1, 2, 3, 4, 5, 6, 7]);
+    const REVERSED: u8x8 = A.reverse();
+    const ROTATED: u8x8 = A.rotate_elements_left::<3>();
+    const SHIFTED: u8x8 = A.shift_elements_right::<2>(9);
+    const INTERLEAVED: (u8x8, u8x8) = A.interleave(REVERSED);
+    const RESIZED: Simd<u8, 4> = A.resize::<4>(0);
+    const EXTRACTED: Simd<u8, 2> = A.extract::<5, 2>();
+    const SWIZZLED: Simd<u8, 4> = simd_swizzle!(A, [7, 0, 7, 1]);
+    const MASK: Mask<i8, 4> = Mask::from_array([true, false, false, false]).reverse();
+    assert_eq!(REVERSED.to_array(), [7, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(ROTATED.to_array(), [3, 4, 5, 6, 7, 0, 1, 2]);
+    assert_eq!(SHIFTED.to_array(), [9, 9, 0, 1, 2, 3, 4, 5]);
+    assert_eq!(INTERLEAVED.0.to_array(), [0, 7, 1, 6, 2, 5, 3, 4]);
+    assert_eq!(RESIZED.to_array(), [0, 1, 2, 3]);
+    assert_eq!(EXTRACTED.to_array(), [5, 6]);
+    assert_eq!(SWIZZLED.to_array(), [7, 0, 7, 1]);
+    assert_eq!(MASK.to_array(), [false, false, false, true]);
+}
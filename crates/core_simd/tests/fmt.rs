@@ -0,0 +1,62 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::{Mask, Simd};
+
+#[test]
+fn integers() {
+    let x = Simd::from_array([0i32, -1, 42, i32::MAX]);
+    assert_eq!(format!("{x}"), "[0, -1, 42, 2147483647]");
+    assert_eq!(format!("{x:+}"), "[+0, -1, +42, +2147483647]");
+    assert_eq!(format!("{x:>3}"), "[  0,  -1,  42, 2147483647]");
+    assert_eq!(format!("{x:x}"), "[0, ffffffff, 2a, 7fffffff]");
+    assert_eq!(format!("{x:#X}"), "[0x0, 0xFFFFFFFF, 0x2A, 0x7FFFFFFF]");
+    assert_eq!(
+        format!("{:#o}", x.resize::<3>(0)),
+        "[0o0, 0o37777777777, 0o52]"
+    );
+    assert_eq!(
+        format!("{:#06b}", Simd::from_array([1u8, 6])),
+        "[0b0001, 0b0110]"
+    );
+}
+
+#[test]
+fn floats() {
+    let x = Simd::from_array([1.5f32, -0.25, 1000.0]);
+    assert_eq!(format!("{x}"), "[1.5, -0.25, 1000]");
+    assert_eq!(format!("{x:.2}"), "[1.50, -0.25, 1000.00]");
+    assert_eq!(format!("{x:e}"), "[1.5e0, -2.5e-1, 1e3]");
+    assert_eq!(format!("{x:E}"), "[1.5E0, -2.5E-1, 1E3]");
+}
+
+#[test]
+fn pointers() {
+    let ptr = Simd::<*const u8, 2>::from_array([core::ptr::null(), 16 as *const u8]);
+    assert_eq!(format!("{ptr:p}"), "[0x0, 0x10]");
+}
+
+#[test]
+fn masks() {
+    let mask = Mask::<i8, 8>::from_array([true, true, false, false, true, false, true, false]);
+    assert_eq!(format!("{mask}"), "11001010");
+    assert_eq!(format!("{mask:-^12}"), "--11001010--");
+    assert_eq!(format!("{mask:<10}|"), "11001010  |");
+    assert_eq!(format!("{mask:4}"), "11001010");
+    assert_eq!(format!("{}", Mask::<i64, 1>::splat(false)), "0");
+}
+
+#[test]
+fn mask_integers() {
+    let mask = Mask::<i16, 3>::from_array([true, false, true]);
+    assert_eq!(format!("{mask:x}"), "[ffff, 0, ffff]");
+    assert_eq!(format!("{mask:#06X}"), "[0xFFFF, 0x0000, 0xFFFF]");
+    assert_eq!(format!("{mask:o}"), "[177777, 0, 177777]");
+    assert_eq!(
+        format!("{:#b}", Mask::<i8, 2>::from_array([false, true])),
+        "[0b0, 0b11111111]"
+    );
+    assert_eq!(
+        format!("{:>4x}", Mask::<i8, 2>::from_array([false, true])),
+        "[   0,   ff]"
+    );
+}
@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+#![feature(f16)]
+
+use core_simd::simd::{Mask, Simd};
+
+#[test]
+fn f16_sum_product() {
+    let vectors = [
+        Simd::<f16, 4>::from_array([1.0, 2.0, -0.5, 0.0]),
+        Simd::from_array([0.5, 3.0, 4.0, 1.0]),
+        Simd::from_array([2.0, 0.25, 1.0, -1.0]),
+    ];
+    let sum: Simd<f16, 4> = vectors.iter().sum();
+    assert_eq!(sum.to_array(), [3.5, 5.25, 4.5, 0.0]);
+    assert_eq!(vectors.into_iter().sum::<Simd<f16, 4>>(), sum);
+
+    let product: Simd<f16, 4> = vectors.iter().product();
+    assert_eq!(product.to_array(), [1.0, 1.5, -2.0, 0.0]);
+    assert_eq!(vectors.into_iter().product::<Simd<f16, 4>>(), product);
+
+    let empty: [Simd<f16, 2>; 0] = [];
+    assert_eq!(empty.iter().sum::<Simd<f16, 2>>(), Simd::splat(0.0));
+    assert_eq!(empty.iter().product::<Simd<f16, 2>>(), Simd::splat(1.0));
+}
+
+#[test]
+fn mask_folds() {
+    let masks = [
+        Mask::<i16, 4>::from_array([true, false, false, true]),
+        Mask::from_array([true, true, false, false]),
+        Mask::from_array([true, false, false, false]),
+    ];
+    assert_eq!(Mask::any_of(masks).to_array(), [true, true, false, true]);
+    assert_eq!(Mask::all_of(masks).to_array(), [true, false, false, false]);
+    assert_eq!(
+        Mask::any_of(masks.iter()),
+        Mask::any_of(masks.iter().copied())
+    );
+    assert_eq!(Mask::all_of(masks.iter()), Mask::all_of(masks));
+
+    let empty: [Mask<i8, 8>; 0] = [];
+    assert_eq!(Mask::any_of(empty), Mask::splat(false));
+    assert_eq!(Mask::all_of(empty), Mask::splat(true));
+}
+
+#[test]
+fn into_iter() {
+    let mut v = Simd::<u32, 4>::from_array([1, 2, 3, 4]);
+    assert_eq!(v.into_iter().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    assert_eq!((&v).into_iter().copied().sum::<u32>(), 10);
+    for x in &mut v {
+        *x *= 10;
+    }
+    assert_eq!(v.to_array(), [10, 20, 30, 40]);
+    assert_eq!(v.into_iter().len(), 4);
+
+    let mask = Mask::<i8, 4>::from_array([false, true, true, false]);
+    let mut lanes = Vec::new();
+    for lane in mask {
+        lanes.push(lane);
+    }
+    assert_eq!(lanes, mask.to_array());
+    assert_eq!(mask.into_iter().position(|x| x), mask.first_set());
+}
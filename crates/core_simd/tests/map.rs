@@ -0,0 +1,43 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::{Mask, Simd};
+
+test_helpers::test_lanes! {
+    fn from_fn<const LANES: usize>() {
+        let v = Simd::<usize, LANES>::from_fn(|i| i * 3);
+        for i in 0..LANES {
+            assert_eq!(v[i], i * 3);
+        }
+
+        let mask = Mask::<i16, LANES>::from_fn(|i| i % 3 == 1);
+        for i in 0..LANES {
+            assert_eq!(mask.test(i), i % 3 == 1);
+        }
+
+        let mut calls = 0;
+        let _ = Simd::<u8, LANES>::from_fn(|i| {
+            assert_eq!(i, calls);
+            calls += 1;
+            0
+        });
+        assert_eq!(calls, LANES);
+    }
+
+    fn map<const LANES: usize>() {
+        test_helpers::test_1(&|x: [i32; LANES]| {
+            let mapped = Simd::from_array(x).map(|x| x.count_ones() as u8);
+            proptest::prop_assert_eq!(mapped.to_array(), x.map(|x| x.count_ones() as u8));
+            Ok(())
+        });
+    }
+
+    fn zip_with<const LANES: usize>() {
+        test_helpers::test_2(&|x: [u16; LANES], y: [i64; LANES]| {
+            let zipped = Simd::from_array(x).zip_with(Simd::from_array(y), |x, y| y.wrapping_shr(x.into()));
+            for i in 0..LANES {
+                proptest::prop_assert_eq!(zipped[i], y[i].wrapping_shr(x[i].into()));
+            }
+            Ok(())
+        });
+    }
+}
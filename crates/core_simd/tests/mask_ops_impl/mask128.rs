@@ -0,0 +1,3 @@
+mask_tests! { mask128x2, 2 }
+mask_tests! { mask128x4, 4 }
+mask_tests! { mask128x8, 8 }
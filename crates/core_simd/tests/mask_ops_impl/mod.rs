@@ -6,4 +6,6 @@ mod mask8;
 mod mask16;
 mod mask32;
 mod mask64;
+#[rustfmt::skip]
+mod mask128;
 mod masksize;
@@ -110,6 +110,41 @@ macro_rules! test_mask_api {
                 assert_eq!(Mask::<$type, 11>::from_bitmask(bitmask), mask);
             }
 
+            #[test]
+            fn roundtrip_bitmask_array_conversion() {
+                fn roundtrip<const N: usize, const W: usize>() {
+                    let values: [bool; N] = core::array::from_fn(|i| i % 3 == 0 || i % 7 == 5);
+                    let mask = Mask::<$type, N>::from_array(values);
+                    let words = mask.to_bitmask_array::<W>();
+                    for i in 0..W * 64 {
+                        let expected = i < N && values[i];
+                        assert_eq!(words[i / 64] & (1 << (i % 64)) != 0, expected);
+                    }
+                    assert_eq!(Mask::<$type, N>::from_bitmask_array(words), mask);
+                    assert_eq!(Mask::<$type, N>::from_bitmask_array([u64::MAX; W]), Mask::splat(true));
+
+                    let lowest = Mask::<$type, N>::from_bitmask_array([words[0]]);
+                    for i in 0..N {
+                        assert_eq!(lowest.test(i), i < 64 && values[i]);
+                    }
+
+                    let mut single = Mask::<$type, N>::splat(false);
+                    assert_eq!(single.first_set(), None);
+                    assert_eq!(single.last_set(), None);
+                    single.set(N - 1, true);
+                    assert_eq!(single.first_set(), Some(N - 1));
+                    assert_eq!(single.last_set(), Some(N - 1));
+                    assert_eq!(mask.first_set(), Some(0));
+                    assert_eq!(mask.last_set(), values.iter().rposition(|x| *x));
+                }
+                roundtrip::<11, 1>();
+                roundtrip::<64, 2>();
+                roundtrip::<100, 2>();
+                roundtrip::<128, 2>();
+                roundtrip::<256, 4>();
+                roundtrip::<300, 5>();
+                roundtrip::<512, 8>();
+            }
 
             #[test]
             fn cast() {
@@ -131,6 +166,7 @@ macro_rules! test_mask_api {
                 cast_impl::<i16>();
                 cast_impl::<i32>();
                 cast_impl::<i64>();
+                cast_impl::<i128>();
                 cast_impl::<isize>();
             }
 
@@ -160,6 +196,7 @@ mod mask_api {
     test_mask_api! { i16 }
     test_mask_api! { i32 }
     test_mask_api! { i64 }
+    test_mask_api! { i128 }
     test_mask_api! { isize }
 }
 
@@ -0,0 +1,43 @@
+#![feature(portable_simd)]
+use core_simd::simd::{
+    Simd,
+    cmp::SimdPartialEq,
+    native::{self, NATIVE_BITS, f32xN, i128xN, mask8xN, u8xN},
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn native_width() {
+    assert!([128, 256, 512].contains(&NATIVE_BITS));
+    if cfg!(all(target_feature = "avx512f", target_feature = "avx512bw")) {
+        assert_eq!(NATIVE_BITS, 512);
+    } else if cfg!(target_feature = "avx2") {
+        assert_eq!(NATIVE_BITS, 256);
+    }
+
+    assert_eq!(size_of::<u8xN>() * 8, NATIVE_BITS);
+    assert_eq!(size_of::<f32xN>() * 8, NATIVE_BITS);
+    assert_eq!(size_of::<i128xN>() * 8, NATIVE_BITS);
+    assert_eq!(native::lanes::<u8>(), NATIVE_BITS / 8);
+    assert_eq!(native::lanes::<f64>(), NATIVE_BITS / 64);
+    assert_eq!(
+        native::lanes::<*const u8>(),
+        NATIVE_BITS / usize::BITS as usize
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn native_ops() {
+    const LANES: usize = native::lanes::<u8>();
+    let x: Simd<u8, LANES> = u8xN::splat(3);
+    let mask: mask8xN = x.simd_eq(Simd::splat(3));
+    assert!(mask.all());
+    assert_eq!((x + x).to_array(), [6; LANES]);
+}
@@ -0,0 +1,103 @@
+#![feature(portable_simd)]
+use core_simd::simd::{
+    Simd,
+    num::{SimdNum, SimdSigned},
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Computes `a * x + y` and reduces it, using only `SimdNum`.
+fn axpy_sum<V: SimdNum>(a: V, x: V, y: V) -> V::Scalar {
+    let mut out = a * x;
+    out += y;
+    out.simd_clamp(V::ZERO, V::ONE + V::ONE + V::ONE)
+        .reduce_sum()
+}
+
+/// Computes `alpha * x + y` with a scalar `alpha`, using only `SimdNum`.
+fn axpy<V: SimdNum>(alpha: V::Scalar, x: V, y: V) -> (V, V::Scalar) {
+    (V::splat(alpha) * x + y, alpha)
+}
+
+/// Returns the largest magnitude, using only `SimdSigned`.
+fn max_magnitude<V: SimdSigned>(x: V) -> (V::Scalar, V) {
+    (x.abs().reduce_max(), -x.signum())
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_num() {
+    let x = Simd::from_array([0, 1, 2, 3]);
+    assert_eq!(axpy_sum(Simd::<i32, 4>::splat(2), x, Simd::splat(-1)), 7);
+    assert_eq!(
+        axpy_sum(
+            Simd::<u8, 4>::splat(2),
+            Simd::from_array([0, 1, 2, 3]),
+            Simd::splat(0)
+        ),
+        8
+    );
+    assert_eq!(
+        axpy_sum(
+            Simd::<f32, 4>::splat(0.5),
+            Simd::from_array([0.0, 1.0, 2.0, 3.0]),
+            Simd::splat(0.5)
+        ),
+        5.0
+    );
+
+    assert_eq!(Simd::<u16, 4>::ZERO, Simd::splat(0));
+    assert_eq!(Simd::<f64, 2>::ONE, Simd::splat(1.0));
+    assert_eq!(
+        SimdNum::abs(Simd::<u8, 2>::from_array([0, 255])).to_array(),
+        [0, 255]
+    );
+    assert_eq!(
+        SimdNum::simd_min(
+            Simd::<f32, 2>::from_array([f32::NAN, 1.0]),
+            Simd::splat(0.0)
+        )
+        .to_array(),
+        [0.0, 0.0]
+    );
+    assert_eq!(
+        SimdNum::reduce_min(Simd::<u32, 4>::from_array([4, 2, 3, 9])),
+        2
+    );
+    assert_eq!(
+        SimdNum::reduce_product(Simd::<i64, 4>::from_array([4, 2, 3, -1])),
+        -24
+    );
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_splat() {
+    let (out, alpha) = axpy(3, Simd::<u8, 4>::from_array([0, 1, 2, 3]), Simd::splat(1));
+    assert_eq!(out.to_array(), [1, 4, 7, 10]);
+    assert_eq!(alpha, 3);
+
+    let (out, _) = axpy(
+        -0.5,
+        Simd::<f64, 2>::from_array([2.0, 4.0]),
+        Simd::splat(1.0),
+    );
+    assert_eq!(out.to_array(), [0.0, -1.0]);
+    assert_eq!(<Simd<i16, 8> as SimdNum>::splat(-2), Simd::splat(-2));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_signed() {
+    let (max, sign) = max_magnitude(Simd::<i8, 4>::from_array([3, -7, 0, 1]));
+    assert_eq!(max, 7);
+    assert_eq!(sign.to_array(), [-1, 1, 0, -1]);
+
+    let (max, sign) = max_magnitude(Simd::<f64, 4>::from_array([3.0, -7.5, 0.0, -0.0]));
+    assert_eq!(max, 7.5);
+    assert_eq!(sign.to_array(), [-1.0, 1.0, -1.0, 1.0]);
+}
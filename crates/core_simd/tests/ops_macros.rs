@@ -726,30 +726,168 @@ macro_rules! impl_float_tests {
                         Ok(())
                     });
                 }
-            }
 
-            #[cfg(feature = "std")]
-            mod std {
-                use std_float::StdFloat;
-
-                use super::*;
-                test_helpers::test_lanes! {
-                    fn sqrt<const LANES: usize>() {
-                        test_helpers::test_unary_elementwise(
-                            &Vector::<LANES>::sqrt,
-                            &Scalar::sqrt,
-                            &|_| true,
-                        )
+                fn sqrt<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::sqrt,
+                        &Scalar::sqrt,
+                        &|_| true,
+                    )
+                }
+
+                fn mul_add<const LANES: usize>() {
+                    test_helpers::test_ternary_elementwise(
+                        &Vector::<LANES>::mul_add,
+                        &Scalar::mul_add,
+                        &|_, _, _| true,
+                    )
+                }
+
+                fn recip_estimate<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let v = Vector::<LANES>::from_array(x);
+                        let exact = v.recip().to_array();
+                        let estimate = v.recip_estimate().to_array();
+                        let fast = v.recip_fast().to_array();
+                        for i in 0..LANES {
+                            if x[i].is_normal() && exact[i].is_normal() {
+                                let error = |y: Scalar| ((y - exact[i]) / exact[i]).abs();
+                                proptest::prop_assert!(error(estimate[i]) <= (1. / 256.) as Scalar);
+                                proptest::prop_assert!(error(fast[i]) <= (1. / 2097152.) as Scalar);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn rsqrt_estimate<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let v = Vector::<LANES>::from_array(x);
+                        let exact = v.sqrt().recip().to_array();
+                        let estimate = v.rsqrt_estimate().to_array();
+                        let fast = v.rsqrt_fast().to_array();
+                        for i in 0..LANES {
+                            if x[i].is_normal() && exact[i].is_normal() {
+                                let error = |y: Scalar| ((y - exact[i]) / exact[i]).abs();
+                                proptest::prop_assert!(error(estimate[i]) <= (1. / 256.) as Scalar);
+                                proptest::prop_assert!(error(fast[i]) <= (1. / 2097152.) as Scalar);
+                            } else if x[i].is_normal() && x[i] < 0. {
+                                proptest::prop_assert!(estimate[i].is_nan() && fast[i].is_nan());
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn recip_rsqrt_fast_extremes<const LANES: usize>() {
+                    // Targets may flush these estimates to zero or overflow
+                    for x in [
+                        Scalar::MAX,
+                        -Scalar::MAX,
+                        Scalar::MAX / 2.,
+                        Scalar::MIN_POSITIVE / 2.,
+                        -Scalar::MIN_POSITIVE / 4.,
+                        Scalar::MIN_POSITIVE / 64.,
+                    ] {
+                        let v = Vector::<LANES>::splat(x);
+                        assert_eq!(v.recip_fast(), v.recip());
+                        if x > 0. {
+                            assert_eq!(v.rsqrt_fast(), v.sqrt().recip());
+                        }
                     }
 
-                    fn mul_add<const LANES: usize>() {
-                        test_helpers::test_ternary_elementwise(
-                            &Vector::<LANES>::mul_add,
-                            &Scalar::mul_add,
-                            &|_, _, _| true,
-                        )
+                    // Elements in range are still refined when other elements are not
+                    let mut x = [1.; LANES];
+                    x[0] = Scalar::MAX;
+                    let v = Vector::<LANES>::from_array(x);
+                    assert_eq!(v.recip_fast()[0], v.recip()[0]);
+                    assert_eq!(v.rsqrt_fast()[0], v.sqrt().recip()[0]);
+                    for i in 1..LANES {
+                        assert!((v.recip_fast()[i] - 1.).abs() <= (1. / 2097152.) as Scalar);
+                        assert!((v.rsqrt_fast()[i] - 1.).abs() <= (1. / 2097152.) as Scalar);
                     }
                 }
+
+                fn next_up<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_up,
+                        &Scalar::next_up,
+                        &|_| true,
+                    )
+                }
+
+                fn next_down<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise(
+                        &Vector::<LANES>::next_down,
+                        &Scalar::next_down,
+                        &|_| true,
+                    )
+                }
+
+                fn frexp<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [Scalar; LANES]| {
+                        let v = Vector::<LANES>::from_array(x);
+                        let (m, e) = v.frexp();
+                        let ilogb = v.ilogb();
+                        test_helpers::prop_assert_biteq!(m.ldexp(e).to_array(), x);
+                        for i in 0..LANES {
+                            if x[i].is_finite() && x[i] != 0. {
+                                proptest::prop_assert!((0.5..1.).contains(&m[i].abs()));
+                                proptest::prop_assert_eq!(ilogb[i], e[i] - 1);
+                            } else {
+                                test_helpers::prop_assert_biteq!(m[i], x[i]);
+                                proptest::prop_assert_eq!(e[i], 0);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn ldexp<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [Scalar; LANES], n: [i32; LANES]| {
+                        // Keep most exponents in a range where the result is interesting
+                        let n = n.map(|n| n % (2 * Scalar::MAX_EXP));
+                        let v = Vector::<LANES>::from_array(x);
+                        let result = v.ldexp(n.into());
+                        test_helpers::prop_assert_biteq!(result.to_array(), v.scalbn(n.into()).to_array());
+                        for i in 0..LANES {
+                            let pow2 = (2. as Scalar).powi(n[i]);
+                            let expected = x[i] * pow2;
+                            if pow2.is_normal() && (expected.is_normal() || !x[i].is_finite() || x[i] == 0.) {
+                                test_helpers::prop_assert_biteq!(result[i], expected);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn ldexp_special<const LANES: usize>() {
+                    let min_exp = Scalar::MIN_EXP - Scalar::MANTISSA_DIGITS as i32;
+                    let min_subnormal = Scalar::from_bits(1);
+                    let ldexp = |x: Scalar, n: i32| Vector::<LANES>::splat(x).ldexp(core_simd::simd::Simd::splat(n))[0];
+                    assert_eq!(ldexp(1., min_exp), min_subnormal);
+                    assert_eq!(ldexp(-0.75, min_exp), -min_subnormal);
+                    assert_eq!(ldexp(1.5, min_exp - 1), min_subnormal);
+                    assert_eq!(ldexp(1., min_exp - 1), 0.);
+                    assert_eq!(ldexp(min_subnormal, -min_exp), 1.);
+                    assert_eq!(ldexp(Scalar::MAX, 1), Scalar::INFINITY);
+                    assert_eq!(ldexp(Scalar::MIN, i32::MAX), Scalar::NEG_INFINITY);
+                    assert_eq!(ldexp(Scalar::MAX, i32::MIN), 0.);
+                    assert_eq!(ldexp(Scalar::MAX, -Scalar::MAX_EXP), 1. - Scalar::EPSILON / 2.);
+                    assert!(ldexp(Scalar::NAN, 1).is_nan());
+                    assert_eq!(ldexp(-0., 10).to_bits(), (-0. as Scalar).to_bits());
+                }
+
+                fn recip_rsqrt_fast_special<const LANES: usize>() {
+                    let zero = Vector::<LANES>::splat(0.);
+                    let inf = Vector::<LANES>::splat(Scalar::INFINITY);
+                    assert_eq!(zero.recip_fast(), inf);
+                    assert_eq!((-zero).recip_fast(), -inf);
+                    assert_eq!(inf.recip_fast(), zero);
+                    assert_eq!(zero.rsqrt_fast(), inf);
+                    assert_eq!(inf.rsqrt_fast(), zero);
+                    assert!(Vector::<LANES>::splat(Scalar::NAN).rsqrt_fast().is_nan().all());
+                }
             }
         }
     }
@@ -0,0 +1,71 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::{Mask, ParseSimdError, Simd, num::SimdInt};
+
+test_helpers::test_lanes! {
+    fn round_trip<const LANES: usize>() {
+        test_helpers::test_2(&|x: [i64; LANES], y: [f64; LANES]| {
+            let x = Simd::from_array(x);
+            proptest::prop_assert_eq!(format!("{x:?}").parse(), Ok(x));
+            proptest::prop_assert_eq!(format!("{x:#?}").parse(), Ok(x));
+            proptest::prop_assert_eq!(format!("{x}").parse(), Ok(x));
+
+            let y = Simd::from_array(y);
+            let parsed = format!("{y:?}").parse::<Simd<f64, LANES>>().unwrap();
+            test_helpers::prop_assert_biteq!(parsed.to_array(), y.to_array());
+
+            let mask = x.is_negative();
+            proptest::prop_assert_eq!(format!("{mask:?}").parse(), Ok(mask));
+            Ok(())
+        });
+    }
+}
+
+#[test]
+fn splat() {
+    assert_eq!(" 7 ".parse(), Ok(Simd::<u8, 4>::splat(7)));
+    assert_eq!("[7; 4]".parse(), Ok(Simd::<u8, 4>::splat(7)));
+    assert_eq!("[ -1.5 ;4 ]".parse(), Ok(Simd::<f32, 4>::splat(-1.5)));
+    assert_eq!("true".parse(), Ok(Mask::<i8, 8>::splat(true)));
+}
+
+#[test]
+fn errors() {
+    let parse = |s: &str| s.parse::<Simd<u8, 4>>().unwrap_err();
+
+    let error = parse("[1, 2, 3, 256]");
+    assert_eq!(error.lane(), Some(3));
+    assert!(error.element_error().is_some());
+    assert_eq!(
+        error.to_string(),
+        "invalid element 3: number too large to fit in target type"
+    );
+
+    let error = parse("[1, 2, 3]");
+    assert_eq!(error.lane(), None);
+    assert_eq!(error.element_error(), None);
+    assert_eq!(error.to_string(), "expected 4 elements, found 3");
+    assert_eq!(
+        parse("[1, 2, 3, 4, 5]").to_string(),
+        "expected 4 elements, found 5"
+    );
+    assert_eq!(parse("[]").to_string(), "expected 4 elements, found 0");
+    assert_eq!(parse("[1; 3]").to_string(), "expected 4 elements, found 3");
+
+    assert_eq!(parse("[1, 2, 3, 4").to_string(), "invalid vector syntax");
+    assert_eq!(parse("1, 2, 3, 4]").to_string(), "invalid vector syntax");
+    assert_eq!(parse("[1; x]").to_string(), "invalid vector syntax");
+
+    let error = parse("x");
+    assert_eq!(error.lane(), None);
+    assert!(error.element_error().is_some());
+    assert_eq!(
+        parse("[x; 4]").to_string(),
+        "invalid element: invalid digit found in string"
+    );
+
+    let error = "[true, yes]".parse::<Mask<i32, 2>>().unwrap_err();
+    assert_eq!(error.lane(), Some(1));
+    assert!(std::error::Error::source(&error).is_some());
+    let _: &ParseSimdError<std::str::ParseBoolError> = &error;
+}
@@ -1,4 +1,5 @@
 #![feature(portable_simd)]
+#![feature(f16)]
 
 macro_rules! float_rounding_test {
     { $scalar:tt, $int_scalar:tt } => {
@@ -85,6 +86,33 @@ macro_rules! float_rounding_test {
                         },
                     ).unwrap();
                 }
+
+                fn to_int_round<const LANES: usize>() {
+                    use core_simd::simd::num::{RoundingMode, SimdFloat};
+                    let modes: [(RoundingMode, fn(Scalar) -> Scalar); 4] = [
+                        (RoundingMode::NearestEven, Scalar::round_ties_even),
+                        (RoundingMode::Floor, Scalar::floor),
+                        (RoundingMode::Ceil, Scalar::ceil),
+                        (RoundingMode::Truncate, Scalar::trunc),
+                    ];
+                    for (mode, round) in modes {
+                        test_helpers::test_1(&|x: [Scalar; LANES]| {
+                            let (ints, clipped) = Vector::from_array(x).to_int_round_checked::<IntScalar>(mode);
+                            let (bytes, bytes_clipped) = Vector::from_array(x).to_int_round_checked::<u8>(mode);
+                            test_helpers::prop_assert_biteq!(ints.to_array(), Vector::from_array(x).to_int_round::<IntScalar>(mode).to_array());
+                            test_helpers::prop_assert_biteq!(bytes.to_array(), Vector::from_array(x).to_int_round::<u8>(mode).to_array());
+                            for i in 0..LANES {
+                                let r = round(x[i]);
+                                test_helpers::prop_assert_biteq!(ints[i], r as IntScalar);
+                                test_helpers::prop_assert_biteq!(bytes[i], r as u8);
+                                let int_range = IntScalar::MIN as Scalar..-(IntScalar::MIN as Scalar);
+                                assert_eq!(clipped.test(i), !int_range.contains(&r), "{} rounded to {}", x[i], r);
+                                assert_eq!(bytes_clipped.test(i), !(0.0..256.0).contains(&r), "{} rounded to {}", x[i], r);
+                            }
+                            Ok(())
+                        });
+                    }
+                }
             }
         }
     }
@@ -92,3 +120,52 @@ macro_rules! float_rounding_test {
 
 float_rounding_test! { f32, i32 }
 float_rounding_test! { f64, i64 }
+
+mod to_int_round_wide {
+    use core_simd::simd::{Simd, num::RoundingMode, prelude::*};
+
+    #[test]
+    fn f16_to_i32() {
+        let x = Simd::<f16, 4>::from_array([f16::NEG_INFINITY, f16::INFINITY, f16::NAN, 1.0]);
+        let (ints, clipped) = x.to_int_round_checked::<i32>(RoundingMode::NearestEven);
+        assert_eq!(ints.to_array(), [i32::MIN, i32::MAX, 0, 1]);
+        assert_eq!(clipped.to_array(), [true, true, true, false]);
+    }
+
+    #[test]
+    fn f16_to_i64() {
+        let x = Simd::<f16, 4>::from_array([f16::MAX, f16::MIN, 0.5, -1.5]);
+        let (ints, clipped) = x.to_int_round_checked::<i64>(RoundingMode::NearestEven);
+        assert_eq!(ints.to_array(), [65504, -65504, 0, -2]);
+        assert_eq!(clipped.to_array(), [false; 4]);
+
+        let x = Simd::<f16, 2>::from_array([f16::NEG_INFINITY, f16::INFINITY]);
+        let (ints, clipped) = x.to_int_round_checked::<i64>(RoundingMode::Floor);
+        assert_eq!(ints.to_array(), [i64::MIN, i64::MAX]);
+        assert_eq!(clipped.to_array(), [true, true]);
+    }
+
+    #[test]
+    fn f16_to_u32() {
+        let x = Simd::<f16, 4>::from_array([-1.0, f16::MAX, f16::INFINITY, -0.4]);
+        let (ints, clipped) = x.to_int_round_checked::<u32>(RoundingMode::NearestEven);
+        assert_eq!(ints.to_array(), [0, 65504, u32::MAX, 0]);
+        assert_eq!(clipped.to_array(), [true, false, true, false]);
+    }
+
+    #[test]
+    fn f32_to_i128() {
+        let x = f32x4::from_array([1e38, f32::MAX, f32::NEG_INFINITY, -(2.0f32.powi(127))]);
+        let (ints, clipped) = x.to_int_round_checked::<i128>(RoundingMode::Truncate);
+        assert_eq!(
+            ints.to_array(),
+            [1e38f32 as i128, i128::MAX, i128::MIN, i128::MIN]
+        );
+        assert_eq!(clipped.to_array(), [false, true, true, false]);
+
+        let x = f32x4::from_array([f32::MAX, f32::INFINITY, -1.0, 0.0]);
+        let (ints, clipped) = x.to_int_round_checked::<u128>(RoundingMode::Ceil);
+        assert_eq!(ints.to_array(), [f32::MAX as u128, u128::MAX, 0, 0]);
+        assert_eq!(clipped.to_array(), [false, true, true, false]);
+    }
+}
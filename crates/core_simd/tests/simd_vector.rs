@@ -0,0 +1,129 @@
+#![feature(portable_simd)]
+use core_simd::simd::{
+    Mask, Select, Simd, SimdElement, SimdVector, SimdWidth, SimdWidthOf, cmp::SimdPartialEq,
+    num::SimdNum,
+};
+
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen_test::*;
+
+#[cfg(target_arch = "wasm32")]
+wasm_bindgen_test_configure!(run_in_browser);
+
+/// Sums a slice 128 bits at a time.
+fn sum<T>(slice: &[T]) -> T
+where
+    T: SimdWidth<128> + core::ops::Add<Output = T> + Default,
+    T::Vector: SimdNum<Scalar = T>,
+{
+    let lanes = <T::Vector as SimdVector>::LANES;
+    let mut chunks = slice.chunks_exact(lanes);
+    let mut acc = <T::Vector as SimdNum>::ZERO;
+    for chunk in &mut chunks {
+        acc += T::Vector::from_slice(chunk);
+    }
+    chunks
+        .remainder()
+        .iter()
+        .fold(acc.reduce_sum(), |a, b| a + *b)
+}
+
+/// Keeps the elements of `a` that are greater than `b` and not equal to `c`.
+fn select_masked<V>(a: V, b: V, c: V) -> V
+where
+    V: SimdNum + SimdVector + SimdPartialEq<Mask = <V as SimdVector>::Mask>,
+{
+    let mut mask = a.simd_gt(b);
+    mask &= !a.simd_eq(c);
+    mask.select(a, V::ZERO)
+}
+
+/// Converts to a vector with the same number of elements.
+fn splat_cast<V: SimdVector, U: SimdElement>(_: V, value: U) -> V::Cast<U> {
+    V::Cast::<U>::splat(value)
+}
+
+/// Converts to a vector with the same width in bits.
+fn splat_same_width<V: SimdVector, U: SimdWidthOf<V>>(_: V, value: U) -> V::SameWidth<U> {
+    V::SameWidth::<U>::splat(value)
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn lanes() {
+    assert_eq!(<Simd<u8, 4> as SimdVector>::LANES, 4);
+    assert_eq!(<<u8 as SimdWidth<64>>::Vector as SimdVector>::LANES, 8);
+    assert_eq!(<<i128 as SimdWidth<512>>::Vector as SimdVector>::LANES, 4);
+    assert_eq!(<<f32 as SimdWidth<256>>::Vector as SimdVector>::LANES, 8);
+    assert_eq!(
+        <<*const u8 as SimdWidth<128>>::Vector as SimdVector>::LANES,
+        128 / usize::BITS as usize
+    );
+
+    let mask: <Simd<f64, 2> as SimdVector>::Mask = Mask::<i64, 2>::splat(true);
+    assert!(mask.all());
+
+    let cast: Simd<u16, 4> = splat_cast(Simd::<f64, 4>::splat(1.0), 2u16);
+    assert_eq!(cast, Simd::splat(2));
+
+    assert_eq!(<Simd<u16, 3> as SimdVector>::BITS, 48);
+    assert_eq!(<<f64 as SimdWidth<256>>::Vector as SimdVector>::BITS, 256);
+    let words: <u32 as SimdWidth<{ <Simd<u8, 16> as SimdVector>::BITS }>>::Vector = Simd::splat(1);
+    assert_eq!(words, Simd::<u32, 4>::splat(1));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn same_width() {
+    let words: Simd<u32, 4> = splat_same_width(Simd::<u8, 16>::splat(0), 1u32);
+    assert_eq!(words, Simd::splat(1));
+
+    let floats: Simd<f32, 16> = splat_same_width(Simd::<f64, 8>::splat(0.0), 2.0f32);
+    assert_eq!(floats, Simd::splat(2.0));
+
+    let wide: Simd<u128, 2> = splat_same_width(Simd::<i8, 32>::splat(0), 3u128);
+    assert_eq!(wide, Simd::splat(3));
+
+    let bytes: Simd<u8, 8> = splat_same_width(Simd::<f32, 2>::splat(0.0), 4u8);
+    assert_eq!(bytes, Simd::splat(4));
+
+    let x = 0u8;
+    let pointers = splat_same_width(Simd::<u64, 4>::splat(0), &raw const x);
+    assert_eq!(
+        <<Simd<u64, 4> as SimdVector>::SameWidth<*const u8> as SimdVector>::LANES,
+        256 / usize::BITS as usize
+    );
+    assert!(pointers.to_array().iter().all(|p| *p == &raw const x));
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn generic_mask() {
+    let a = Simd::from_array([1, 5, 3, 7]);
+    let selected = select_masked(a, Simd::splat(2), Simd::splat(7));
+    assert_eq!(selected.to_array(), [0, 5, 3, 0]);
+
+    let a = Simd::from_array([1.0, 5.0, 3.0, 7.0]);
+    let selected = select_masked(a, Simd::splat(2.0), Simd::splat(5.0));
+    assert_eq!(selected.to_array(), [0.0, 0.0, 3.0, 7.0]);
+}
+
+#[test]
+#[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+fn width_generic() {
+    let bytes: Vec<u8> = (0..21).collect();
+    assert_eq!(
+        sum(&bytes),
+        bytes.iter().fold(0u8, |a, b| a.wrapping_add(*b))
+    );
+
+    let ints: Vec<i64> = (-10..13).collect();
+    assert_eq!(sum(&ints), ints.iter().sum());
+
+    let floats: Vec<f32> = (0..9).map(|x| x as f32).collect();
+    assert_eq!(sum(&floats), 36.0);
+
+    let mut out = [0u16; 8];
+    <u16 as SimdWidth<128>>::Vector::splat(3).copy_to_slice(&mut out);
+    assert_eq!(out, [3; 8]);
+}
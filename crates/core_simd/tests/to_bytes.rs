@@ -1,6 +1,7 @@
 #![feature(portable_simd)]
+#![feature(f16)]
 
-use core_simd::simd::{Simd, ToBytes};
+use core_simd::simd::{Mask, Simd, ToBytes};
 
 #[test]
 fn byte_convert() {
@@ -18,3 +19,97 @@ fn byte_convert() {
     assert_eq!(Simd::<u32, 2>::from_be_bytes(be_bytes), int);
     assert_eq!(Simd::<u32, 2>::from_le_bytes(le_bytes), int);
 }
+
+#[test]
+fn byte_convert_u128() {
+    let int = Simd::<u128, 2>::from_array([0x0123_4567_89ab_cdef_fedc_ba98_7654_3210, 42]);
+    assert_eq!(int[0].to_be_bytes(), int.to_be_bytes()[..16]);
+    assert_eq!(int[1].to_le_bytes(), int.to_le_bytes()[16..]);
+    assert_eq!(Simd::<u128, 2>::from_be_bytes(int.to_be_bytes()), int);
+    assert_eq!(Simd::<u128, 2>::from_le_bytes(int.to_le_bytes()), int);
+}
+
+#[test]
+fn byte_convert_f16() {
+    let float = Simd::<f16, 4>::from_array([1.0, -2.5, f16::INFINITY, 0.1]);
+    assert_eq!(float[1].to_be_bytes(), float.to_be_bytes()[2..4]);
+    assert_eq!(float[3].to_le_bytes(), float.to_le_bytes()[6..]);
+    assert_eq!(Simd::<f16, 4>::from_be_bytes(float.to_be_bytes()), float);
+    assert_eq!(Simd::<f16, 4>::from_le_bytes(float.to_le_bytes()), float);
+}
+
+#[test]
+fn byte_convert_mask() {
+    let mask = Mask::<i16, 4>::from_array([true, false, false, true]);
+    let bytes = [0xff, 0xff, 0, 0, 0, 0, 0xff, 0xff];
+    assert_eq!(mask.to_be_bytes().to_array(), bytes);
+    assert_eq!(mask.to_le_bytes().to_array(), bytes);
+    assert_eq!(Mask::<i16, 4>::from_be_bytes(Simd::from_array(bytes)), mask);
+    assert_eq!(Mask::<i16, 4>::from_ne_bytes(mask.to_ne_bytes()), mask);
+}
+
+#[test]
+fn byte_convert_invalid_mask() {
+    let bytes = Simd::from_array([1, 0, 0, 0, 0xff, 0xff, 0x80, 0]);
+    let lenient = Mask::from_array([true, false, true, true]);
+    assert_eq!(Mask::<i16, 4>::from_le_bytes(bytes), lenient);
+    assert_eq!(Mask::<i16, 4>::from_be_bytes(bytes), lenient);
+    assert_eq!(Mask::<i16, 4>::try_from_bytes(bytes), None);
+
+    let bytes = Simd::from_array([0, 0, 0xff, 0xff]);
+    let mask = Mask::from_array([false, true]);
+    assert_eq!(Mask::<i16, 2>::try_from_bytes(bytes), Some(mask));
+}
+
+#[test]
+fn byte_convert_slices() {
+    let vectors = [
+        Simd::<u32, 2>::from_array([0xdeadbeef, 0x8badf00d]),
+        Simd::from_array([1, 2]),
+        Simd::from_array([u32::MAX, 0]),
+    ];
+    let mut be = [0; 24];
+    let mut le = [0; 24];
+    let mut ne = [0; 24];
+    Simd::copy_to_be_bytes(&vectors, &mut be);
+    Simd::copy_to_le_bytes(&vectors, &mut le);
+    Simd::copy_to_ne_bytes(&vectors, &mut ne);
+    for (i, vector) in vectors.iter().enumerate() {
+        assert_eq!(be[i * 8..][..8], vector.to_be_bytes()[..]);
+        assert_eq!(le[i * 8..][..8], vector.to_le_bytes()[..]);
+        assert_eq!(ne[i * 8..][..8], vector.to_ne_bytes()[..]);
+    }
+
+    let mut decoded = [Simd::splat(0); 3];
+    Simd::copy_from_be_bytes(&be, &mut decoded);
+    assert_eq!(decoded, vectors);
+    Simd::copy_from_le_bytes(&le, &mut decoded);
+    assert_eq!(decoded, vectors);
+    Simd::copy_from_ne_bytes(&ne, &mut decoded);
+    assert_eq!(decoded, vectors);
+
+    let masks = [Mask::<i8, 4>::from_array([true, false, true, true]); 2];
+    let mut bytes = [0; 8];
+    Mask::copy_to_le_bytes(&masks, &mut bytes);
+    let mut decoded = [Mask::splat(false); 2];
+    Mask::copy_from_le_bytes(&bytes, &mut decoded);
+    assert_eq!(decoded, masks);
+
+    let mut decoded = [Mask::splat(false); 2];
+    assert_eq!(Mask::try_copy_from_bytes(&bytes, &mut decoded), Ok(()));
+    assert_eq!(decoded, masks);
+
+    bytes[5] = 2;
+    let mut decoded = [Mask::splat(false); 2];
+    assert_eq!(Mask::try_copy_from_bytes(&bytes, &mut decoded), Err(1));
+    assert_eq!(decoded, [masks[0], Mask::splat(false)]);
+    Mask::copy_from_le_bytes(&bytes, &mut decoded);
+    assert_eq!(decoded, [masks[0], Mask::splat(true)]);
+}
+
+#[test]
+#[should_panic]
+fn byte_convert_slices_length_mismatch() {
+    let mut bytes = [0; 7];
+    Simd::copy_to_le_bytes(&[Simd::<u16, 2>::splat(0); 2], &mut bytes);
+}
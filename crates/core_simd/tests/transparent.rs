@@ -0,0 +1,171 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::{Mask, Select, Simd, TransparentElement, simd_swizzle};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Meters(f32);
+
+// Safety: `Meters` is a transparent wrapper around `f32`
+unsafe impl TransparentElement for Meters {
+    type Inner = f32;
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Wrap(Meters);
+
+// Safety: `Wrap` is a transparent wrapper around `Meters`
+unsafe impl TransparentElement for Wrap {
+    type Inner = Meters;
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+struct Id(u32);
+
+// Safety: `Id` is a transparent wrapper around `u32`
+unsafe impl TransparentElement for Id {
+    type Inner = u32;
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+struct UserId(Id);
+
+// Safety: `UserId` is a transparent wrapper around `Id`
+unsafe impl TransparentElement for UserId {
+    type Inner = Id;
+}
+
+/// An ASCII byte that compares case-insensitively, unlike its representation.
+#[derive(Copy, Clone, Debug)]
+#[repr(transparent)]
+struct Ascii(u8);
+
+impl PartialEq for Ascii {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+// Safety: `Ascii` is a transparent wrapper around `u8`
+unsafe impl TransparentElement for Ascii {
+    type Inner = u8;
+}
+
+#[test]
+fn convert() {
+    let inner = Simd::from_array([1.0, 2.0, 3.0, 4.0]);
+    let meters = Simd::<Meters, 4>::from_inner(inner);
+    assert_eq!(
+        meters.to_array(),
+        [Meters(1.0), Meters(2.0), Meters(3.0), Meters(4.0)]
+    );
+    assert_eq!(meters.into_inner(), inner);
+
+    let ids = Simd::<UserId, 4>::from_inner(Simd::from_inner(Simd::from_array([1, 2, 3, 4])));
+    assert_eq!(ids[2], UserId(Id(3)));
+
+    let meters = Simd::<Meters, 3>::splat(Meters(2.0));
+    assert_eq!(meters.into_inner().to_array(), [2.0; 3]);
+
+    let wrapped = Simd::<Wrap, 5>::from_inner(Simd::from_inner(Simd::splat(1.5)));
+    assert_eq!(wrapped.to_array(), [Wrap(Meters(1.5)); 5]);
+    assert_eq!(wrapped.into_inner().into_inner(), Simd::splat(1.5));
+}
+
+#[test]
+fn compare() {
+    let a = Simd::from_array([Meters(1.0), Meters(-0.0), Meters(f32::NAN), Meters(4.0)]);
+    let b = Simd::from_array([Meters(1.0), Meters(0.0), Meters(f32::NAN), Meters(4.0)]);
+    assert_ne!(a, b);
+    assert_eq!(a.resize::<2>(Meters(0.0)), b.resize::<2>(Meters(0.0)));
+    assert!(a.resize::<2>(Meters(0.0)) >= b.resize::<2>(Meters(0.0)));
+
+    let ids = Simd::from_array([UserId(Id(1)), UserId(Id(2))]);
+    assert_eq!(ids, ids);
+    assert_ne!(ids, ids.reverse());
+    let set = std::collections::HashSet::from([ids, ids, ids.reverse()]);
+    assert_eq!(set.len(), 2);
+
+    // Comparisons use the newtype's `PartialEq`, not its representation
+    let a = Simd::from_array(*b"Simd").map(Ascii);
+    let b = Simd::from_array(*b"SIMD").map(Ascii);
+    let c = Simd::from_array(*b"SIMs").map(Ascii);
+    assert_eq!(a == b, a.to_array() == b.to_array());
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert!(!(a != b));
+
+    let w = Simd::<Wrap, 3>::splat(Wrap(Meters(1.0)));
+    assert_eq!(w, w);
+    let v = Simd::from_array([1.0, 2.0, f32::NAN, 4.0, 5.0]).map(|x| Wrap(Meters(x)));
+    assert_ne!(v, v);
+    assert_eq!(
+        v.resize::<2>(Wrap(Meters(0.0))),
+        v.resize::<2>(Wrap(Meters(0.0)))
+    );
+}
+
+#[test]
+fn rearrange() {
+    let ids = Simd::from_array([Id(0), Id(1), Id(2), Id(3)]);
+    assert_eq!(
+        simd_swizzle!(ids, [3, 1, 2, 0]).to_array(),
+        [Id(3), Id(1), Id(2), Id(0)]
+    );
+    let (lo, hi) = ids.deinterleave(ids.rotate_elements_left::<1>());
+    assert_eq!(lo.to_array(), [Id(0), Id(2), Id(1), Id(3)]);
+    assert_eq!(hi.to_array(), [Id(1), Id(3), Id(2), Id(0)]);
+
+    let mask = Mask::<i32, 4>::from_array([true, false, true, false]);
+    let selected = mask.select(ids, Simd::splat(Id(9)));
+    assert_eq!(selected.to_array(), [Id(0), Id(9), Id(2), Id(9)]);
+
+    let w = Simd::from_array([1.0, 2.0, 3.0]).map(|x| Wrap(Meters(x)));
+    let (lo, hi) = w.interleave(w);
+    assert_eq!(lo.to_array().map(|x| x.0.0), [1.0, 1.0, 2.0]);
+    assert_eq!(hi.to_array().map(|x| x.0.0), [2.0, 3.0, 3.0]);
+    assert_eq!(w.reverse().to_array().map(|x| x.0.0), [3.0, 2.0, 1.0]);
+
+    let ids = Simd::from_array([Id(0), Id(1), Id(2), Id(3), Id(4)]);
+    let mask = Mask::<i32, 5>::from_array([true, false, true, false, true]);
+    let selected = mask.select(ids, Simd::splat(Id(9)));
+    assert_eq!(selected.to_array(), [Id(0), Id(9), Id(2), Id(9), Id(4)]);
+    let selected = 0b01010.select(ids, Simd::splat(Id(9)));
+    assert_eq!(selected.to_array(), [Id(9), Id(1), Id(9), Id(3), Id(9)]);
+}
+
+#[test]
+fn memory() {
+    let slice = [Meters(1.0), Meters(2.0), Meters(3.0)];
+    let loaded = Simd::<Meters, 4>::load_or_default(&slice);
+    assert_eq!(
+        loaded.to_array(),
+        [Meters(1.0), Meters(2.0), Meters(3.0), Meters(0.0)]
+    );
+
+    let gathered = Simd::gather_or_default(&slice, Simd::from_array([2, 0, 5, 1]));
+    assert_eq!(
+        gathered.to_array(),
+        [Meters(3.0), Meters(1.0), Meters(0.0), Meters(2.0)]
+    );
+
+    let mut out = [Meters(0.0); 3];
+    gathered.store_select(&mut out, Mask::splat(true));
+    assert_eq!(out, [Meters(3.0), Meters(1.0), Meters(0.0)]);
+
+    let slice = [1.0, 2.0, 3.0, 4.0].map(|x| Wrap(Meters(x)));
+    let loaded = Simd::<Wrap, 5>::load_or_default(&slice);
+    assert_eq!(loaded.to_array().map(|x| x.0.0), [1.0, 2.0, 3.0, 4.0, 0.0]);
+
+    let gathered = Simd::gather_or_default(&slice, Simd::from_array([3, 7, 0]));
+    assert_eq!(gathered.to_array().map(|x| x.0.0), [4.0, 0.0, 1.0]);
+
+    let mut out = [Wrap(Meters(0.0)); 2];
+    gathered.store_select(&mut out, Mask::splat(true));
+    assert_eq!(out.map(|x| x.0.0), [4.0, 0.0]);
+    gathered.scatter(&mut out, Simd::from_array([1, 0, 5]));
+    assert_eq!(out.map(|x| x.0.0), [0.0, 4.0]);
+}
@@ -0,0 +1,64 @@
+#![feature(portable_simd)]
+
+use core_simd::simd::prelude::*;
+
+fn wide_ops<const N: usize>() {
+    let a = Simd::<u8, N>::from_array(core::array::from_fn(|i| i as u8));
+    let b = Simd::<u8, N>::splat(3);
+
+    let sum = a + b;
+    for i in 0..N {
+        assert_eq!(sum[i], (i as u8).wrapping_add(3));
+    }
+    assert_eq!(
+        a.reduce_sum(),
+        (0..N).fold(0u8, |acc, i| acc.wrapping_add(i as u8))
+    );
+    assert_eq!(a.reduce_max(), (N - 1).min(255) as u8);
+
+    let reversed = a.reverse();
+    assert_eq!(reversed[0], (N - 1) as u8);
+    assert_eq!(reversed.reverse(), a);
+    let rotated = a.rotate_elements_left::<1>();
+    assert_eq!(rotated[N - 1], 0);
+
+    let mask = a.simd_lt(b);
+    assert_eq!(mask.first_set(), Some(0));
+    assert_eq!(mask.last_set(), (0..N).rposition(|i| (i as u8) < 3));
+    let selected = mask.select(b, a);
+    assert_eq!(selected[0], 3);
+
+    let wide: Simd<u16, N> = a.cast();
+    assert_eq!(wide[N - 1], (N - 1) as u8 as u16);
+    let floats: Simd<f32, N> = a.cast();
+    assert_eq!(floats.reduce_max(), a.reduce_max() as f32);
+}
+
+#[test]
+fn lanes_128() {
+    wide_ops::<128>();
+}
+
+#[test]
+fn lanes_256() {
+    wide_ops::<256>();
+}
+
+#[test]
+fn lanes_384() {
+    wide_ops::<384>();
+}
+
+#[test]
+fn lanes_512() {
+    wide_ops::<512>();
+}
+
+#[test]
+fn bitmask_array_select() {
+    let mask = Mask::<i8, 128>::from_bitmask_array([1, 1 << 63]);
+    let selected = mask.select(Simd::splat(1u8), Simd::splat(0));
+    assert_eq!(selected[0], 1);
+    assert_eq!(selected[127], 1);
+    assert_eq!(selected.reduce_sum(), 2);
+}
@@ -0,0 +1,25 @@
+//! Checks that `mul_add` and `sqrt` link on targets without a system math library.
+//!
+//! Lanes without hardware support lower to `fmaf`, `fma`, `sqrtf` and `sqrt` calls, which
+//! `compiler_builtins` must provide.
+#![no_std]
+#![no_main]
+#![feature(portable_simd)]
+
+use core::hint::black_box;
+use core_simd::simd::{f32x4, f64x2, num::SimdFloat};
+
+#[panic_handler]
+fn panic(_: &core::panic::PanicInfo<'_>) -> ! {
+    loop {}
+}
+
+#[unsafe(no_mangle)]
+pub extern "C" fn _start() -> ! {
+    let x = black_box(f32x4::splat(2.0));
+    let y = black_box(f64x2::splat(2.0));
+    black_box((x.mul_add(x, x).sqrt(), y.mul_add(y, y).sqrt()));
+    loop {
+        core::hint::spin_loop();
+    }
+}
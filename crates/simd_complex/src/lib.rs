@@ -0,0 +1,254 @@
+#![no_std]
+#![cfg_attr(feature = "as_crate", feature(portable_simd))]
+//! Complex numbers in SIMD vectors.
+#[cfg(not(feature = "as_crate"))]
+use core::simd;
+#[cfg(feature = "as_crate")]
+use core_simd::simd;
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use simd::{cmp::SimdPartialOrd, num::SimdFloat, Select, Simd, SimdElement};
+
+/// A SIMD vector of `N` complex numbers.
+///
+/// The real and imaginary parts are stored in separate vectors, which is the layout that
+/// makes arithmetic efficient.  Interleaved `[re, im, re, im, ...]` data can be converted with
+/// [`from_interleaved`](Self::from_interleaved) and
+/// [`copy_to_interleaved`](Self::copy_to_interleaved).
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(portable_simd)]
+/// # use core_simd::simd::f32x2;
+/// # use simd_complex::SimdComplex;
+/// let a = SimdComplex::from_interleaved(&[1.0f32, 2.0, 3.0, -1.0]);
+/// let b = SimdComplex::new(f32x2::splat(0.0), f32x2::splat(1.0));
+///
+/// let mut out = [0.0; 4];
+/// (a * b).copy_to_interleaved(&mut out);
+/// assert_eq!(out, [-2.0, 1.0, 1.0, 3.0]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct SimdComplex<T, const N: usize>
+where
+    T: SimdElement,
+{
+    /// The real parts.
+    pub re: Simd<T, N>,
+    /// The imaginary parts.
+    pub im: Simd<T, N>,
+}
+
+impl<T, const N: usize> SimdComplex<T, N>
+where
+    T: SimdElement,
+{
+    /// Constructs complex numbers from their real and imaginary parts.
+    #[inline]
+    pub const fn new(re: Simd<T, N>, im: Simd<T, N>) -> Self {
+        Self { re, im }
+    }
+
+    /// Constructs a vector with all elements set to the complex number `re + im * i`.
+    #[inline]
+    pub fn splat(re: T, im: T) -> Self {
+        Self::new(Simd::splat(re), Simd::splat(im))
+    }
+
+    /// Reads `N` complex numbers from a slice of interleaved real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than `2 * N`.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub fn from_interleaved(slice: &[T]) -> Self {
+        assert!(
+            slice.len() >= 2 * N,
+            "slice length must be at least twice the number of elements"
+        );
+        let lo = Simd::from_slice(&slice[..N]);
+        let hi = Simd::from_slice(&slice[N..2 * N]);
+        let (re, im) = lo.deinterleave(hi);
+        Self::new(re, im)
+    }
+
+    /// Writes the `N` complex numbers to a slice as interleaved real and imaginary parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice's length is less than `2 * N`.
+    #[inline]
+    #[track_caller]
+    pub fn copy_to_interleaved(self, slice: &mut [T]) {
+        assert!(
+            slice.len() >= 2 * N,
+            "slice length must be at least twice the number of elements"
+        );
+        let (lo, hi) = self.re.interleave(self.im);
+        lo.copy_to_slice(&mut slice[..N]);
+        hi.copy_to_slice(&mut slice[N..2 * N]);
+    }
+}
+
+/// Computes `a * b + c`, fusing the operations only if the target has FMA instructions.
+///
+/// Without hardware support, [`SimdFloat::mul_add`] calls a library function for each element,
+/// which is much slower than rounding twice.
+#[inline(always)]
+fn mul_add<V>(a: V, b: V, c: V) -> V
+where
+    V: SimdFloat + Add<Output = V> + Mul<Output = V>,
+{
+    if cfg!(any(
+        target_feature = "fma",
+        all(target_arch = "aarch64", target_feature = "neon")
+    )) {
+        a.mul_add(b, c)
+    } else {
+        a * b + c
+    }
+}
+
+macro_rules! impl_complex {
+    { $($ty:ty),* } => {
+        $(
+        impl<const N: usize> SimdComplex<$ty, N> {
+            /// Returns the complex conjugate of each element.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn conj(self) -> Self {
+                Self::new(self.re, -self.im)
+            }
+
+            /// Returns the squared magnitude of each element, `re * re + im * im`.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn norm_sqr(self) -> Simd<$ty, N> {
+                mul_add(self.re, self.re, self.im * self.im)
+            }
+
+            /// Computes `self * a + b` for each element, using fused multiply-adds.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::new(
+                    self.re.mul_add(a.re, (-self.im).mul_add(a.im, b.re)),
+                    self.re.mul_add(a.im, self.im.mul_add(a.re, b.im)),
+                )
+            }
+        }
+
+        impl<const N: usize> Neg for SimdComplex<$ty, N> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self::new(-self.re, -self.im)
+            }
+        }
+
+        impl<const N: usize> Add for SimdComplex<$ty, N> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.re + rhs.re, self.im + rhs.im)
+            }
+        }
+
+        impl<const N: usize> Sub for SimdComplex<$ty, N> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.re - rhs.re, self.im - rhs.im)
+            }
+        }
+
+        impl<const N: usize> Mul for SimdComplex<$ty, N> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(
+                    mul_add(self.re, rhs.re, -(self.im * rhs.im)),
+                    mul_add(self.re, rhs.im, self.im * rhs.re),
+                )
+            }
+        }
+
+        impl<const N: usize> Div for SimdComplex<$ty, N> {
+            type Output = Self;
+
+            /// Divides using Smith's algorithm, which avoids overflow and underflow in
+            /// intermediate results.
+            ///
+            /// Elements with a part larger than half the maximum value are halved first, so the
+            /// sums in the numerator and denominator can't overflow.
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                let Self { re: a, im: b } = self;
+                let Self { re: c, im: d } = rhs;
+
+                // Halving both operands doesn't change the quotient, and only loses precision in
+                // parts that are too small to affect it
+                let limit = Simd::splat(<$ty>::MAX / 2.0);
+                let huge = a.abs().simd_gt(limit)
+                    | b.abs().simd_gt(limit)
+                    | c.abs().simd_gt(limit)
+                    | d.abs().simd_gt(limit);
+                let scale = huge.select(Simd::splat(0.5), Simd::splat(1.0));
+                let (a, b, c, d) = (a * scale, b * scale, c * scale, d * scale);
+
+                // Divide out whichever part of the divisor is larger in magnitude
+                let real_larger = c.abs().simd_ge(d.abs());
+                let large = real_larger.select(c, d);
+                let small = real_larger.select(d, c);
+                let ratio = small / large;
+                let denom = mul_add(small, ratio, large);
+
+                // Both cases share a formula for the real part after swapping the parts of the
+                // dividend, but the imaginary part changes sign
+                let x = real_larger.select(a, b);
+                let y = real_larger.select(b, a);
+                let re = mul_add(y, ratio, x);
+                let im = real_larger.select(mul_add(-x, ratio, y), mul_add(x, ratio, -y));
+                Self::new(re / denom, im / denom)
+            }
+        }
+
+        impl<const N: usize> AddAssign for SimdComplex<$ty, N> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const N: usize> SubAssign for SimdComplex<$ty, N> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const N: usize> MulAssign for SimdComplex<$ty, N> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<const N: usize> DivAssign for SimdComplex<$ty, N> {
+            #[inline]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+        )*
+    }
+}
+
+impl_complex! { f32, f64 }
@@ -0,0 +1,302 @@
+#![feature(portable_simd)]
+#![feature(f128)]
+#![expect(internal_features)]
+#![feature(cfg_target_has_reliable_f16_f128)]
+
+/// Sums of products for computing reference results.
+trait Dot: Sized {
+    /// Computes `a * b + c * d` with at most a few rounding errors relative to the result.
+    fn dot(a: Self, b: Self, c: Self, d: Self) -> Self;
+}
+
+/// Reference results for `f64` can't be computed in a wider type, so the products and their sum
+/// are computed with error-free transformations, as if in twice the precision.
+impl Dot for f64 {
+    fn dot(a: f64, b: f64, c: f64, d: f64) -> f64 {
+        let (p, q) = (a * b, c * d);
+        let (p_error, q_error) = (a.mul_add(b, -p), c.mul_add(d, -q));
+        let sum = p + q;
+        let sum_error = (p - (sum - (sum - p))) + (q - (sum - p));
+        sum + (p_error + q_error + sum_error)
+    }
+}
+
+/// Products of `f64`s are exact in `f128`.
+#[cfg(target_has_reliable_f128)]
+impl Dot for f128 {
+    fn dot(a: f128, b: f128, c: f128, d: f128) -> f128 {
+        a * b + c * d
+    }
+}
+
+macro_rules! impl_tests {
+    { $name:ident, $scalar:tt, $wide:ty } => {
+        mod $name {
+            use core_simd::simd::Simd;
+            use simd_complex::SimdComplex;
+            use super::Dot;
+
+            /// The type reference results are computed in, which is wider than the tested type
+            /// or uses `Dot` to avoid rounding products.
+            type Wide = $wide;
+
+            type Complex<const LANES: usize> = SimdComplex<$scalar, LANES>;
+
+            /// Builds two vectors of complex numbers from two arrays of parts.
+            fn complex<const LANES: usize>(
+                x: [$scalar; LANES],
+                y: [$scalar; LANES],
+            ) -> (Complex<LANES>, Complex<LANES>) {
+                let (x, y) = (Simd::from_array(x), Simd::from_array(y));
+                (Complex::new(x, y), Complex::new(y.reverse(), x.rotate_elements_left::<1>()))
+            }
+
+            /// Applies a scalar function to each pair of elements.
+            fn scalar<const LANES: usize>(
+                a: Complex<LANES>,
+                b: Complex<LANES>,
+                f: impl Fn(($scalar, $scalar), ($scalar, $scalar)) -> ($scalar, $scalar),
+            ) -> ([$scalar; LANES], [$scalar; LANES]) {
+                let mut re = [0.0; LANES];
+                let mut im = [0.0; LANES];
+                for i in 0..LANES {
+                    (re[i], im[i]) = f((a.re[i], a.im[i]), (b.re[i], b.im[i]));
+                }
+                (re, im)
+            }
+
+            /// Approximates the magnitude of a complex number, which only scales tolerances.
+            ///
+            /// This is computed in `f64`, since `f128` doesn't have reliable math functions.
+            fn hypot(x: Wide, y: Wide) -> Wide {
+                (x as f64).hypot(y as f64) as Wide
+            }
+
+            /// Complex multiplication in a wider or more accurate type.
+            fn mul((a, b): (Wide, Wide), (c, d): (Wide, Wide)) -> (Wide, Wide) {
+                (Dot::dot(a, c, -b, d), Dot::dot(a, d, b, c))
+            }
+
+            /// Complex division in a wider or more accurate type, or `None` if it would lose
+            /// precision to overflow or underflow.
+            fn div((a, b): (Wide, Wide), (c, d): (Wide, Wide)) -> Option<(Wide, Wide)> {
+                let denom = Dot::dot(c, c, d, d);
+                let scale = hypot(a, b) * hypot(c, d);
+                let exact = |x: Wide| x.is_finite() && x >= Wide::MIN_POSITIVE / Wide::EPSILON;
+                (exact(denom) && exact(scale)).then(|| {
+                    (Dot::dot(a, c, b, d) / denom, Dot::dot(b, c, -a, d) / denom)
+                })
+            }
+
+            /// Returns whether `x` is finite in the tested type, and large enough that underflow
+            /// in products doesn't dominate rounding errors.
+            fn in_range(x: Wide) -> bool {
+                x <= <$scalar>::MAX as Wide
+                    && x >= <$scalar>::MIN_POSITIVE as Wide / <$scalar>::EPSILON as Wide
+            }
+
+            /// Checks that each element of `v` is within a few ulps of `expected`.
+            ///
+            /// Each expected value is `(re, im, scale)`, where `scale` bounds the magnitude of the
+            /// terms summed to compute it, since cancellation makes the error relative to the
+            /// terms rather than the result.  Elements whose scale isn't in range are skipped.
+            fn check_close<const LANES: usize>(
+                op: &str,
+                v: Complex<LANES>,
+                expected: [Option<(Wide, Wide, Wide)>; LANES],
+            ) -> Result<(), proptest::test_runner::TestCaseError> {
+                for (i, expected) in expected.into_iter().enumerate() {
+                    let Some((re, im, scale)) = expected else { continue };
+                    if !in_range(scale) {
+                        continue;
+                    }
+                    let tolerance = 8.0 * <$scalar>::EPSILON as Wide * scale;
+                    let (v_re, v_im) = (v.re[i] as Wide, v.im[i] as Wide);
+                    proptest::prop_assert!(
+                        (v_re - re).abs() <= tolerance && (v_im - im).abs() <= tolerance,
+                        "{} element {}: got ({}, {}), expected ({}, {})",
+                        op, i, v.re[i], v.im[i], re as $scalar, im as $scalar
+                    );
+                }
+                Ok(())
+            }
+
+            /// Computes a reference result for each pair of elements in a wider type.
+            fn reference<const LANES: usize>(
+                a: Complex<LANES>,
+                b: Complex<LANES>,
+                f: impl Fn((Wide, Wide), (Wide, Wide)) -> Option<(Wide, Wide, Wide)>,
+            ) -> [Option<(Wide, Wide, Wide)>; LANES] {
+                core::array::from_fn(|i| {
+                    f((a.re[i] as Wide, a.im[i] as Wide), (b.re[i] as Wide, b.im[i] as Wide))
+                })
+            }
+
+            test_helpers::test_lanes! {
+                fn arithmetic<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let (a, b) = complex(x, y);
+                        let check = |v: Complex<LANES>, (re, im): ([$scalar; LANES], [$scalar; LANES])| {
+                            test_helpers::prop_assert_biteq!(v.re.to_array(), re);
+                            test_helpers::prop_assert_biteq!(v.im.to_array(), im);
+                            Ok(())
+                        };
+                        check(a + b, scalar(a, b, |(a, b), (c, d)| (a + c, b + d)))?;
+                        check(a - b, scalar(a, b, |(a, b), (c, d)| (a - c, b - d)))?;
+                        check(-a, scalar(a, b, |(a, b), _| (-a, -b)))?;
+                        check(a.conj(), scalar(a, b, |(a, b), _| (a, -b)))?;
+
+                        // The error of a product is bounded relative to its magnitude, and so is
+                        // the error of a quotient
+                        let magnitude = |(re, im): (Wide, Wide)| Some((re, im, hypot(re, im)));
+                        check_close("mul", a * b, reference(a, b, |x, y| magnitude(mul(x, y))))?;
+
+                        // Smith's algorithm loses precision to subnormal intermediate results, so
+                        // only compare operands that are well within range.  Subnormal dividends
+                        // are checked separately with exact results.
+                        check_close("div", a / b, reference(a, b, |x, y| {
+                            let normal = in_range(hypot(x.0, x.1)) && in_range(hypot(y.0, y.1));
+                            div(x, y).filter(|_| normal).and_then(magnitude)
+                        }))?;
+
+                        check_close("mul_add", a.mul_add(b, a), reference(a, b, |x, y| {
+                            let (re, im) = mul(x, y);
+                            let scale = hypot(x.0, x.1) * (hypot(y.0, y.1) + 1.0);
+                            Some((re + x.0, im + x.1, scale))
+                        }))?;
+
+                        let mut assigned = a;
+                        assigned *= b;
+                        assigned += a;
+                        assigned -= b;
+                        assigned /= b;
+                        let expected = ((a * b + a) - b) / b;
+                        check(assigned, (expected.re.to_array(), expected.im.to_array()))?;
+
+                        let norm = a.norm_sqr().to_array();
+                        for i in 0..LANES {
+                            let (x, y) = (x[i] as Wide, y[i] as Wide);
+                            let expected = Dot::dot(x, x, y, y);
+                            if in_range(expected) {
+                                let error = (norm[i] as Wide - expected).abs();
+                                proptest::prop_assert!(error <= 4.0 * <$scalar>::EPSILON as Wide * expected);
+                            }
+                        }
+                        Ok(())
+                    });
+                }
+
+                fn interleaved<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [$scalar; LANES], y: [$scalar; LANES]| {
+                        let mut interleaved = [0.0; 128];
+                        for i in 0..LANES {
+                            interleaved[2 * i] = x[i];
+                            interleaved[2 * i + 1] = y[i];
+                        }
+                        let c = Complex::<LANES>::from_interleaved(&interleaved);
+                        test_helpers::prop_assert_biteq!(c.re.to_array(), x);
+                        test_helpers::prop_assert_biteq!(c.im.to_array(), y);
+
+                        let mut out = [0.0; 128];
+                        c.copy_to_interleaved(&mut out);
+                        test_helpers::prop_assert_biteq!(out, interleaved);
+                        Ok(())
+                    });
+                }
+            }
+
+            #[test]
+            fn division() {
+                let a = Complex::<2>::from_interleaved(&[1.0, 2.0, 1e30, 1e30]);
+                let b = Complex::<2>::from_interleaved(&[3.0, 4.0, 1e30, 1e30]);
+                let q = a / b;
+                assert!((q.re[0] - 0.44).abs() < 1e-6 && (q.im[0] - 0.08).abs() < 1e-6);
+                assert_eq!((q.re[1], q.im[1]), (1.0, 0.0));
+            }
+
+            #[test]
+            fn division_overflow() {
+                // The naive formula overflows computing `c * c + d * d`
+                let big = <$scalar>::MAX / 4.0;
+                let a = Complex::<2>::from_interleaved(&[big, big, big, 0.0]);
+                let b = Complex::<2>::from_interleaved(&[big, big, 0.0, big]);
+                let q = a / b;
+                assert_eq!((q.re[0], q.im[0]), (1.0, 0.0));
+                assert_eq!((q.re[1], q.im[1]), (0.0, -1.0));
+
+                // Divisors and quotients near the maximum value
+                let huge = <$scalar>::MAX * 0.75;
+                let half = <$scalar>::MAX * 0.5;
+                let a = Complex::<2>::from_interleaved(&[big, big, half, half]);
+                let b = Complex::<2>::from_interleaved(&[huge, huge, 0.75, 0.75]);
+                let q = a / b;
+                assert!((q.re[0] - 1.0 / 3.0).abs() <= 4.0 * <$scalar>::EPSILON && q.im[0] == 0.0);
+                let expected = <$scalar>::MAX / 1.5;
+                assert!((q.re[1] - expected).abs() <= 4.0 * <$scalar>::EPSILON * expected);
+                assert_eq!(q.im[1], 0.0);
+            }
+
+            #[test]
+            fn division_underflow() {
+                // The naive formula underflows computing `c * c + d * d`
+                let tiny = <$scalar>::MIN_POSITIVE * 4.0;
+                let a = Complex::<2>::from_interleaved(&[tiny, tiny, tiny, 0.0]);
+                let b = Complex::<2>::from_interleaved(&[tiny, -tiny, 0.0, tiny]);
+                let q = a / b;
+                assert_eq!((q.re[0], q.im[0]), (0.0, 1.0));
+                assert_eq!((q.re[1], q.im[1]), (0.0, -1.0));
+            }
+
+            #[test]
+            fn division_extreme_dividends() {
+                // Subnormal dividends divide exactly by one
+                let min = <$scalar>::from_bits(1);
+                let a = Complex::<2>::from_interleaved(&[min, 0.0, 3.0 * min, -min]);
+                let q = a / Complex::splat(1.0, 0.0);
+                assert_eq!((q.re[0], q.im[0]), (min, 0.0));
+                assert_eq!((q.re[1], q.im[1]), (3.0 * min, -min));
+
+                // Dividends near the maximum value, where the numerator sums would overflow
+                let max = <$scalar>::MAX;
+                let a = Complex::<4>::from_interleaved(&[max, max, max, -max, max, 0.0, max, max]);
+                let b = Complex::<4>::from_interleaved(&[1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 1.0, -1.0]);
+                let q = a / b;
+                assert_eq!((q.re[0], q.im[0]), (max, 0.0));
+                assert_eq!((q.re[1], q.im[1]), (max, -max));
+                assert_eq!((q.re[2], q.im[2]), (0.0, -max));
+                assert_eq!((q.re[3], q.im[3]), (0.0, max));
+            }
+
+            #[test]
+            fn non_finite() {
+                let nan = <$scalar>::NAN;
+                let inf = <$scalar>::INFINITY;
+                let a = Complex::<4>::from_interleaved(&[nan, 0.0, 1.0, 1.0, 1.0, 2.0, inf, 0.0]);
+                let b = Complex::<4>::from_interleaved(&[1.0, 1.0, nan, 0.0, inf, 0.0, 2.0, 0.0]);
+
+                let q = a / b;
+                assert!(q.re[0].is_nan() && q.im[0].is_nan());
+                assert!(q.re[1].is_nan() && q.im[1].is_nan());
+                assert_eq!((q.re[2], q.im[2]), (0.0, 0.0));
+                assert_eq!(q.re[3], inf);
+
+                let p = a * b;
+                assert!(p.re[0].is_nan() && p.im[0].is_nan());
+                assert!(p.re[1].is_nan() && p.im[1].is_nan());
+                assert_eq!(p.re[2], inf);
+                assert_eq!(p.re[3], inf);
+            }
+
+            #[test]
+            #[should_panic]
+            fn from_interleaved_short() {
+                let _ = Complex::<4>::from_interleaved(&[0.0; 7]);
+            }
+        }
+    }
+}
+
+impl_tests! { f32, f32, f64 }
+impl_tests! { f64, f64, f64 }
+#[cfg(target_has_reliable_f128)]
+impl_tests! { f64_wide, f64, f128 }
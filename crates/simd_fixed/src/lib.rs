@@ -0,0 +1,301 @@
+#![no_std]
+#![cfg_attr(feature = "as_crate", feature(portable_simd))]
+//! Fixed-point numbers in SIMD vectors.
+#[cfg(not(feature = "as_crate"))]
+use core::simd;
+#[cfg(feature = "as_crate")]
+use core_simd::simd;
+
+use core::mem;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use simd::{
+    cmp::{SimdOrd, SimdPartialEq},
+    num::{RoundingMode, SimdFloat, SimdInt},
+    Select, Simd,
+};
+
+macro_rules! impl_fixed {
+    {
+        $(
+        $(#[$attr:meta])*
+        $name:ident {
+            bits: $int:ty,
+            wide: $wide:ty,
+            frac: $frac:literal,
+            mul: $mul:ident,
+            $(float: $float:ty => $from_float:ident, $to_float:ident;)*
+        }
+        )*
+    } => {
+        $(
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+        #[repr(transparent)]
+        pub struct $name<const N: usize>(Simd<$int, N>);
+
+        impl<const N: usize> $name<N> {
+            /// Raw transmutation from the underlying integer vector.
+            #[inline]
+            #[must_use]
+            pub const fn from_bits(bits: Simd<$int, N>) -> Self {
+                Self(bits)
+            }
+
+            /// Raw transmutation to the underlying integer vector.
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub const fn to_bits(self) -> Simd<$int, N> {
+                self.0
+            }
+
+            $(
+            #[doc = concat!("Converts each element of an `", stringify!($float), "` vector to the nearest fixed-point value, with ties rounding to even.")]
+            ///
+            /// Values outside the representable range saturate, and NaN is converted to zero.
+            #[inline]
+            #[must_use]
+            pub fn $from_float(x: Simd<$float, N>) -> Self {
+                let scaled = x * Simd::splat((1u64 << $frac) as $float);
+                Self(scaled.to_int_round::<$int>(RoundingMode::NearestEven))
+            }
+
+            #[doc = concat!("Converts each element to an `", stringify!($float), "`.")]
+            #[inline]
+            #[must_use = "method returns a new vector and does not mutate the original value"]
+            pub fn $to_float(self) -> Simd<$float, N> {
+                self.0.cast::<$float>() / Simd::splat((1u64 << $frac) as $float)
+            }
+            )*
+        }
+
+        impl<const N: usize> Neg for $name<N> {
+            type Output = Self;
+
+            /// Negates each element, saturating `-1.0` to the largest value.
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+
+        impl<const N: usize> Add for $name<N> {
+            type Output = Self;
+
+            /// Adds each element, saturating on overflow.
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl<const N: usize> Sub for $name<N> {
+            type Output = Self;
+
+            /// Subtracts each element, saturating on overflow.
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl<const N: usize> Mul for $name<N> {
+            type Output = Self;
+
+            /// Multiplies each element, rounding to nearest with ties towards positive infinity.
+            ///
+            /// The only product that overflows is `-1.0 * -1.0`, which saturates to the
+            /// largest value.
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                Self($mul(self.0, rhs.0))
+            }
+        }
+
+        impl<const N: usize> AddAssign for $name<N> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<const N: usize> SubAssign for $name<N> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<const N: usize> MulAssign for $name<N> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+        )*
+
+        $(
+        impl<const N: usize> MulHighRounding for Simd<$int, N> {
+            #[inline]
+            fn mul_high_rounding(self, rhs: Self) -> Self {
+                let product = self.cast::<$wide>() * rhs.cast::<$wide>();
+                let rounded = (product + Simd::splat(1 << ($frac - 1))) >> $frac;
+                rounded
+                    .simd_min(Simd::splat(<$int>::MAX as $wide))
+                    .cast::<$int>()
+            }
+        }
+        )*
+    }
+}
+
+impl_fixed! {
+    /// A SIMD vector of `N` signed Q15 fixed-point numbers, with 15 fractional bits stored in
+    /// an `i16`.
+    ///
+    /// Each element represents a value in `[-1.0, 1.0)`.  Addition and subtraction saturate,
+    /// and multiplication is the saturating rounding doubling high multiply (`pmulhrsw` on x86,
+    /// `sqrdmulh` on AArch64).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core_simd::simd::f32x4;
+    /// # use simd_fixed::Q15;
+    /// let a = Q15::from_f32(f32x4::from_array([0.5, -0.5, 0.75, -1.0]));
+    /// let b = Q15::from_f32(f32x4::from_array([0.5, 0.25, 0.75, -1.0]));
+    /// assert_eq!(
+    ///     (a * b).to_f32(),
+    ///     f32x4::from_array([0.25, -0.125, 0.5625, 32767.0 / 32768.0])
+    /// );
+    /// assert_eq!((a + a).to_bits()[2], i16::MAX);
+    /// ```
+    Q15 {
+        bits: i16,
+        wide: i32,
+        frac: 15,
+        mul: mul_q15,
+        float: f32 => from_f32, to_f32;
+    }
+
+    /// A SIMD vector of `N` signed Q31 fixed-point numbers, with 31 fractional bits stored in
+    /// an `i32`.
+    ///
+    /// Each element represents a value in `[-1.0, 1.0)`.  Addition and subtraction saturate,
+    /// and multiplication is the saturating rounding doubling high multiply (`sqrdmulh` on
+    /// AArch64).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(portable_simd)]
+    /// # use core_simd::simd::f64x2;
+    /// # use simd_fixed::Q31;
+    /// let a = Q31::from_f64(f64x2::from_array([0.5, -0.25]));
+    /// assert_eq!((a * a).to_f64(), f64x2::from_array([0.25, 0.0625]));
+    /// ```
+    Q31 {
+        bits: i32,
+        wide: i64,
+        frac: 31,
+        mul: mul_q31,
+        float: f32 => from_f32, to_f32;
+        float: f64 => from_f64, to_f64;
+    }
+}
+
+/// The saturating rounding doubling high multiply, computed with widened elements.
+trait MulHighRounding {
+    fn mul_high_rounding(self, rhs: Self) -> Self;
+}
+
+#[inline]
+fn mul_q15<const N: usize>(a: Simd<i16, N>, b: Simd<i16, N>) -> Simd<i16, N> {
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    use core::arch::aarch64;
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64 as x86;
+    // SAFETY: Intrinsics covered by cfg
+    unsafe {
+        #[allow(
+            unreachable_patterns,
+            reason = "avoids writing verbose cfg(not), earlier branches take priority"
+        )]
+        match N {
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            4 => transize(aarch64::vqrdmulh_s16, a, b),
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            8 => transize(aarch64::vqrdmulhq_s16, a, b),
+            #[cfg(target_feature = "ssse3")]
+            8 => fix_mulhrs(transize(x86::_mm_mulhrs_epi16, a, b), a, b),
+            #[cfg(target_feature = "avx2")]
+            16 => fix_mulhrs(transize(x86::_mm256_mulhrs_epi16, a, b), a, b),
+            #[cfg(target_feature = "avx512bw")]
+            32 => fix_mulhrs(transize(x86::_mm512_mulhrs_epi16, a, b), a, b),
+            _ => a.mul_high_rounding(b),
+        }
+    }
+}
+
+#[inline]
+fn mul_q31<const N: usize>(a: Simd<i32, N>, b: Simd<i32, N>) -> Simd<i32, N> {
+    #![allow(unused_imports, unused_unsafe)]
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm64ec"))]
+    use core::arch::aarch64;
+    // SAFETY: Intrinsics covered by cfg
+    unsafe {
+        #[allow(
+            unreachable_patterns,
+            reason = "avoids writing verbose cfg(not), earlier branches take priority"
+        )]
+        match N {
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            2 => transize(aarch64::vqrdmulh_s32, a, b),
+            #[cfg(all(
+                any(target_arch = "aarch64", target_arch = "arm64ec"),
+                target_feature = "neon"
+            ))]
+            4 => transize(aarch64::vqrdmulhq_s32, a, b),
+            _ => a.mul_high_rounding(b),
+        }
+    }
+}
+
+/// `pmulhrsw` wraps `-1.0 * -1.0` to `-1.0`, rather than saturating.
+#[allow(dead_code, reason = "only used on some targets/features")]
+#[inline(always)]
+fn fix_mulhrs<const N: usize>(
+    product: Simd<i16, N>,
+    a: Simd<i16, N>,
+    b: Simd<i16, N>,
+) -> Simd<i16, N> {
+    // The square of any other value is non-negative
+    let overflow = a.simd_eq(b) & product.simd_eq(Simd::splat(i16::MIN));
+    overflow.select(Simd::splat(i16::MAX), product)
+}
+
+#[allow(dead_code, reason = "only used on some targets/features")]
+#[inline(always)]
+unsafe fn transize<T, U, const N: usize>(
+    f: unsafe fn(U, U) -> U,
+    a: Simd<T, N>,
+    b: Simd<T, N>,
+) -> Simd<T, N>
+where
+    T: simd::SimdElement,
+{
+    // SAFETY: Same obligation to use this function as to use mem::transmute_copy.
+    unsafe { mem::transmute_copy(&f(mem::transmute_copy(&a), mem::transmute_copy(&b))) }
+}
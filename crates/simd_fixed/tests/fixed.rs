@@ -0,0 +1,92 @@
+#![feature(portable_simd)]
+
+macro_rules! impl_tests {
+    { $name:ident, $int:tt, $wide:ty, $frac:literal } => {
+        #[allow(non_snake_case)]
+        mod $name {
+            use core_simd::simd::Simd;
+            use simd_fixed::$name;
+
+            /// The saturating rounding doubling high multiply.
+            fn mul(a: $int, b: $int) -> $int {
+                let product = a as $wide * b as $wide + (1 << ($frac - 1));
+                (product >> $frac).min($int::MAX as $wide) as $int
+            }
+
+            test_helpers::test_lanes! {
+                fn arithmetic<const LANES: usize>() {
+                    test_helpers::test_2(&|x: [$int; LANES], y: [$int; LANES]| {
+                        let a = $name::from_bits(Simd::from_array(x));
+                        let b = $name::from_bits(Simd::from_array(y));
+                        let check = |v: $name<LANES>, f: &dyn Fn($int, $int) -> $int| {
+                            for i in 0..LANES {
+                                proptest::prop_assert_eq!(v.to_bits()[i], f(x[i], y[i]));
+                            }
+                            Ok(())
+                        };
+                        check(a * b, &mul)?;
+                        check(a + b, &$int::saturating_add)?;
+                        check(a - b, &$int::saturating_sub)?;
+                        check(-a, &|a, _| a.saturating_neg())?;
+
+                        let mut assigned = a;
+                        assigned *= b;
+                        assigned += a;
+                        assigned -= b;
+                        proptest::prop_assert_eq!(assigned, a * b + a - b);
+                        Ok(())
+                    });
+                }
+
+                fn float_conversion<const LANES: usize>() {
+                    test_helpers::test_1(&|x: [f32; LANES]| {
+                        let fixed = $name::from_f32(Simd::from_array(x)).to_bits();
+                        for i in 0..LANES {
+                            let scaled = x[i] * (1u64 << $frac) as f32;
+                            let expected = if scaled.is_nan() { 0 } else { scaled.round_ties_even() as $int };
+                            proptest::prop_assert_eq!(fixed[i], expected);
+                        }
+                        Ok(())
+                    });
+                    test_helpers::test_1(&|x: [$int; LANES]| {
+                        let float = $name::from_bits(Simd::from_array(x)).to_f32();
+                        for i in 0..LANES {
+                            proptest::prop_assert_eq!(float[i], x[i] as f32 / (1u64 << $frac) as f32);
+                        }
+                        Ok(())
+                    });
+                }
+            }
+
+            #[test]
+            fn saturation() {
+                let min = $name::<4>::from_bits(Simd::splat($int::MIN));
+                let max = $name::<4>::from_bits(Simd::splat($int::MAX));
+                assert_eq!((min * min).to_bits(), Simd::splat($int::MAX));
+                assert_eq!((min * max).to_bits(), Simd::splat($int::MIN + 1));
+                assert_eq!((max * max).to_bits(), Simd::splat($int::MAX - 1));
+                assert_eq!((-min).to_bits(), Simd::splat($int::MAX));
+                assert_eq!((min - max).to_bits(), Simd::splat($int::MIN));
+
+                let one = $name::<4>::from_f32(Simd::from_array([1.0, -1.0, 2.0, f32::NAN]));
+                assert_eq!(one.to_bits().to_array(), [$int::MAX, $int::MIN, $int::MAX, 0]);
+            }
+        }
+    }
+}
+
+impl_tests! { Q15, i16, i32, 15 }
+impl_tests! { Q31, i32, i64, 31 }
+
+#[test]
+fn q31_f64_conversion() {
+    use core_simd::simd::f64x4;
+    use simd_fixed::Q31;
+    let x = f64x4::from_array([0.5, -1.0, 1.0, 1.0 / (1u64 << 31) as f64]);
+    let q = Q31::from_f64(x);
+    assert_eq!(q.to_bits().to_array(), [1 << 30, i32::MIN, i32::MAX, 1]);
+    assert_eq!(
+        q.to_f64().to_array(),
+        [0.5, -1.0, i32::MAX as f64 / (1u64 << 31) as f64, x[3]]
+    );
+}
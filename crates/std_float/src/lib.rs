@@ -13,7 +13,10 @@ use core_simd::simd;
 
 use core::intrinsics::simd as intrinsics;
 
-use simd::Simd;
+use simd::{num::SimdFloat, Simd};
+
+mod poly;
+pub use poly::PolyFloat;
 
 /// This trait provides a possibly-temporary implementation of float functions
 /// that may, in the absence of hardware support, canonicalize to calling an
@@ -33,29 +36,13 @@ use simd::Simd;
 /// It is possible this trait will be replaced in some manner in the future,
 /// when either the compiler or its supporting runtime functions are improved.
 /// For now this trait is available to permit experimentation with SIMD float
-/// operations that may lack hardware support, such as `mul_add`.
-pub impl(self) trait StdFloat: Sized {
-    /// Elementwise fused multiply-add. Computes `(self * a) + b` with only one rounding error,
-    /// yielding a more accurate result than an unfused multiply-add.
-    ///
-    /// Using `mul_add` *may* be more performant than an unfused multiply-add if the target
-    /// architecture has a dedicated `fma` CPU instruction.  However, this is not always
-    /// true, and will be heavily dependent on designing algorithms with specific target
-    /// hardware in mind.
-    #[inline]
-    #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        unsafe { intrinsics::simd_fma(self, a, b) }
-    }
-
-    /// Produces a vector where every element has the square root value
-    /// of the equivalently-indexed element in `self`
-    #[inline]
-    #[must_use = "method returns a new vector and does not mutate the original value"]
-    fn sqrt(self) -> Self {
-        unsafe { intrinsics::simd_fsqrt(self) }
-    }
-
+/// operations that may lack hardware support, such as `sin`.
+///
+/// `mul_add` and `sqrt` are provided by [`SimdFloat`] instead, since they
+/// are available without `std` on targets with the corresponding
+/// instructions.  It is a supertrait of `StdFloat`, so they can still be
+/// called on any `T: StdFloat`.
+pub impl(self) trait StdFloat: SimdFloat {
     /// Produces a vector where every element has the sine of the value
     /// in the equivalently-indexed element in `self`.
     #[inline]
@@ -120,6 +107,94 @@ pub impl(self) trait StdFloat: Sized {
         unsafe { intrinsics::simd_flog10(self) }
     }
 
+    /// Produces a vector where every element has the base-e exponential of the value
+    /// in the equivalently-indexed element in `self`, minus 1, computed accurately
+    /// even when the result is close to zero.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp_m1(self) -> Self;
+
+    /// Produces a vector where every element has the natural logarithm of one plus the
+    /// value in the equivalently-indexed element in `self`, computed accurately even when
+    /// the element is close to zero.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln_1p(self) -> Self;
+
+    /// Produces a vector where every element has the value in the equivalently-indexed
+    /// element in `self` raised to the power of the equivalently-indexed element in `n`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powf(self, n: Self) -> Self;
+
+    /// Produces a vector where every element has the value in the equivalently-indexed
+    /// element in `self` raised to the integer power `n`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn powi(self, n: i32) -> Self;
+
+    /// Produces a vector where every element has the cube root of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cbrt(self) -> Self;
+
+    /// Produces a vector where every element has the length of the hypotenuse of a
+    /// right-angle triangle with legs of the equivalently-indexed elements in `self` and
+    /// `other`, without intermediate overflow or underflow.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn hypot(self, other: Self) -> Self;
+
+    /// Produces a vector where every element has the tangent of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tan(self) -> Self;
+
+    /// Produces vectors where every element has the sine and the cosine, respectively, of
+    /// the value in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin_cos(self) -> (Self, Self);
+
+    /// Produces a vector where every element has the arcsine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// Each element of the result is in the range `[-pi/2, pi/2]`, or NaN if the element
+    /// of `self` is outside the range `[-1, 1]`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn asin(self) -> Self;
+
+    /// Produces a vector where every element has the arccosine of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// Each element of the result is in the range `[0, pi]`, or NaN if the element
+    /// of `self` is outside the range `[-1, 1]`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn acos(self) -> Self;
+
+    /// Produces a vector where every element has the arctangent of the value
+    /// in the equivalently-indexed element in `self`.
+    ///
+    /// Each element of the result is in the range `[-pi/2, pi/2]`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atan(self) -> Self;
+
+    /// Produces a vector where every element has the four quadrant arctangent of the
+    /// equivalently-indexed elements in `self` (`y`) and `other` (`x`).
+    ///
+    /// Each element of the result is in the range `[-pi, pi]`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn atan2(self, other: Self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic sine of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sinh(self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic cosine of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cosh(self) -> Self;
+
+    /// Produces a vector where every element has the hyperbolic tangent of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn tanh(self) -> Self;
+
     /// Returns the smallest integer greater than or equal to each element.
     #[must_use = "method returns a new vector and does not mutate the original value"]
     #[inline]
@@ -161,23 +236,75 @@ pub impl(self) trait StdFloat: Sized {
     fn fract(self) -> Self;
 }
 
-impl<const N: usize> StdFloat for Simd<f16, N> {
-    #[inline]
-    fn fract(self) -> Self {
-        self - self.trunc()
+/// Implements the [`StdFloat`] methods without an intrinsic by applying the scalar
+/// function to each element.
+macro_rules! lanewise_methods {
+    { $ty:ident } => {
+        lanewise_methods! {
+            $ty,
+            unary: exp_m1, ln_1p, cbrt, tan, asin, acos, atan, sinh, cosh, tanh;
+            binary: powf, hypot, atan2
+        }
+
+        #[inline]
+        fn powi(self, n: i32) -> Self {
+            Simd::from_array(self.to_array().map(|x| x.powi(n)))
+        }
+
+        #[inline]
+        fn sin_cos(self) -> (Self, Self) {
+            (StdFloat::sin(self), StdFloat::cos(self))
+        }
+
+        #[inline]
+        fn fract(self) -> Self {
+            self - self.trunc()
+        }
+    };
+    { $ty:ident, unary: $($unary:ident),*; binary: $($binary:ident),* } => {
+        $(
+        #[inline]
+        fn $unary(self) -> Self {
+            Simd::from_array(self.to_array().map($ty::$unary))
+        }
+        )*
+
+        $(
+        #[inline]
+        fn $binary(self, other: Self) -> Self {
+            Simd::from_array(core::array::from_fn(|i| self[i].$binary(other[i])))
+        }
+        )*
+    };
+}
+
+/// Overrides the [`StdFloat`] methods that have a [`PolyFloat`] implementation.
+#[cfg(feature = "poly")]
+macro_rules! poly_methods {
+    { $($func:ident),* } => {
+        $(
+        #[inline]
+        fn $func(self) -> Self {
+            PolyFloat::$func(self)
+        }
+        )*
     }
 }
 
+impl<const N: usize> StdFloat for Simd<f16, N> {
+    lanewise_methods! { f16 }
+}
+
 impl<const N: usize> StdFloat for Simd<f32, N> {
-    #[inline]
-    fn fract(self) -> Self {
-        self - self.trunc()
-    }
+    #[cfg(feature = "poly")]
+    poly_methods! { sin, cos, exp, exp2, ln, log2, log10 }
+
+    lanewise_methods! { f32 }
 }
 
 impl<const N: usize> StdFloat for Simd<f64, N> {
-    #[inline]
-    fn fract(self) -> Self {
-        self - self.trunc()
-    }
+    #[cfg(feature = "poly")]
+    poly_methods! { sin, cos, exp, exp2, ln, log2, log10 }
+
+    lanewise_methods! { f64 }
 }
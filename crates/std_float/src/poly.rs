@@ -0,0 +1,447 @@
+//! Vectorized polynomial implementations of transcendental functions.
+
+use crate::{intrinsics, simd};
+
+use simd::{
+    cmp::{SimdPartialEq, SimdPartialOrd},
+    num::{SimdFloat, SimdInt, SimdUint},
+    Mask, Select, Simd,
+};
+
+/// Vectorized implementations of transcendental functions.
+///
+/// The [`StdFloat`](crate::StdFloat) versions of these functions are implemented with
+/// intrinsics that most targets lower to a call to the platform's `libm` for each element.
+/// The functions in this trait instead reduce each argument to a small interval using
+/// exactly representable constants, then evaluate a minimax polynomial, so the entire
+/// vector is computed with SIMD arithmetic, comparisons, and bit manipulation.
+///
+/// Enabling the `poly` feature of this crate makes [`StdFloat`](crate::StdFloat) use
+/// these implementations for `f32` and `f64` vectors.
+///
+/// # Accuracy
+///
+/// Results are within the following bounds of the correctly rounded result, measured in
+/// units in the last place (ULP) over samples from every binade.  The `f32` bounds are checked
+/// against results computed in `f64`.  The `f64` bounds are checked against results computed in
+/// `f128` where the target supports it, and otherwise against the platform's `libm`, which is
+/// usually but not always correctly rounded.  Special values (zeros, infinities, and NaN) and
+/// subnormal inputs and outputs are handled like the corresponding scalar functions.
+///
+/// | Function | `f32` | `f64` |
+/// |----------|-------|-------|
+/// | `sin`    | 2     | 2     |
+/// | `cos`    | 2     | 2     |
+/// | `exp`    | 1     | 1     |
+/// | `exp2`   | 1     | 1     |
+/// | `ln`     | 1     | 1     |
+/// | `log2`   | 1     | 1     |
+/// | `log10`  | 2     | 2     |
+///
+/// `sin` and `cos` only use the polynomial for arguments with magnitude below `8192.0` (`f32`)
+/// or `1048576.0` (`f64`).  If any element exceeds that, the elements that do are computed
+/// with the same intrinsic as [`StdFloat`](crate::StdFloat).
+pub impl(self) trait PolyFloat: Sized {
+    /// Produces a vector where every element has the sine of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn sin(self) -> Self;
+
+    /// Produces a vector where every element has the cosine of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn cos(self) -> Self;
+
+    /// Produces a vector where every element has the exponential (base e) of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp(self) -> Self;
+
+    /// Produces a vector where every element has the exponential (base 2) of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn exp2(self) -> Self;
+
+    /// Produces a vector where every element has the natural logarithm of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn ln(self) -> Self;
+
+    /// Produces a vector where every element has the base-2 logarithm of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log2(self) -> Self;
+
+    /// Produces a vector where every element has the base-10 logarithm of the value
+    /// in the equivalently-indexed element in `self`.
+    #[must_use = "method returns a new vector and does not mutate the original value"]
+    fn log10(self) -> Self;
+}
+
+/// Building blocks shared by the functions in [`PolyFloat`].
+trait Kernel: Sized {
+    type Scalar;
+    type Int;
+
+    /// Evaluates the polynomial with the given coefficients, lowest order first.
+    fn horner(self, coeffs: &[Self::Scalar]) -> Self;
+
+    /// Rounds each element to the nearest integer (ties to even), returning the result both
+    /// as a float and as an integer.  Only valid for magnitudes well below the mantissa range.
+    fn round_int(self) -> (Self, Self::Int);
+
+    /// Multiplies each element by `2^k`, for any `k` that can produce a finite nonzero result.
+    fn scale(self, k: Self::Int) -> Self;
+
+    /// Computes `exp(hi - lo)`, where `hi - lo` is at most `ln(2) / 2` in magnitude.
+    fn exp_reduced(hi: Self, lo: Self) -> Self;
+
+    /// Computes the sine of each element, or the cosine when `cos` is set, for elements below
+    /// the reduction limit.
+    fn sin_cos_reduced(self, cos: bool) -> Self;
+
+    /// Splits each element into `k` and `f`, where `self = 2^k * (1 + f)` and
+    /// `sqrt(2) / 2 <= 1 + f < sqrt(2)`, and evaluates the polynomial part of `ln(1 + f)`.
+    ///
+    /// Returns `(k, f, f * f / 2, r)`, where `ln(1 + f) = f - f * f / 2 + r`.
+    fn ln_reduced(self) -> (Self, Self, Self, Self);
+
+    /// Replaces the results of a logarithm for zero, negative, infinite, and NaN elements.
+    fn ln_special(self, result: Self) -> Self;
+
+    /// Splits `f - hfsq + r` into a high part with half of the mantissa cleared and a low part.
+    fn ln_split(f: Self, hfsq: Self, r: Self) -> (Self, Self);
+}
+
+macro_rules! impl_poly {
+    { $($ty:ident { int: $int:ident, uint: $uint:ident }),* } => {
+        $(
+        impl<const N: usize> Kernel for Simd<$ty, N> {
+            type Scalar = $ty;
+            type Int = Simd<$int, N>;
+
+            #[inline]
+            fn horner(self, coeffs: &[$ty]) -> Self {
+                let (last, rest) = coeffs.split_last().unwrap();
+                rest.iter().rev().fold(Self::splat(*last), |acc, c| acc * self + Self::splat(*c))
+            }
+
+            #[inline]
+            fn round_int(self) -> (Self, Self::Int) {
+                use consts::$ty::ROUND_MAGIC;
+                // Adding the magic number pushes the fractional bits out of the mantissa, which
+                // rounds to nearest even and leaves the integer in the low bits.
+                let biased = self + Self::splat(ROUND_MAGIC);
+                let int = (biased.to_bits() - Simd::splat(ROUND_MAGIC.to_bits())).cast::<$int>();
+                (biased - Self::splat(ROUND_MAGIC), int)
+            }
+
+            #[inline]
+            fn scale(self, k: Self::Int) -> Self {
+                use consts::$ty::{EXP_BIAS, MANTISSA_BITS};
+                let pow2 = |k: Self::Int| {
+                    Self::from_bits(((k + Simd::splat(EXP_BIAS)) << MANTISSA_BITS as $int).cast::<$uint>())
+                };
+                // Split the scaling in two so that each factor is a normal number, and the
+                // second multiplication is the only one that rounds.
+                let half = k >> 1;
+                self * pow2(half) * pow2(k - half)
+            }
+
+            #[inline]
+            fn exp_reduced(hi: Self, lo: Self) -> Self {
+                use consts::$ty::EXP;
+                let r = hi - lo;
+                let rr = r * r;
+                let c = r - rr * rr.horner(&EXP);
+                Self::splat(1.0) + (r * c / (Self::splat(2.0) - c) - lo + hi)
+            }
+
+            #[inline]
+            fn sin_cos_reduced(self, cos: bool) -> Self {
+                use consts::$ty::{COS, FRAC_2_PI, FRAC_PI_2_PARTS, SIGN_SHIFT, SIN};
+                let (n, q) = (self * Self::splat(FRAC_2_PI)).round_int();
+                let r = FRAC_PI_2_PARTS.iter().fold(self, |r, p| r - n * Self::splat(*p));
+
+                let z = r * r;
+                let sin = r + r * z * z.horner(&SIN);
+                let hz = z * Self::splat(0.5);
+                let w = Self::splat(1.0) - hz;
+                let cos_r = w + (((Self::splat(1.0) - w) - hz) + z * z * z.horner(&COS));
+
+                // cos(x) = sin(x + pi/2), so the cosine is one quadrant ahead
+                let q = if cos { q + Simd::splat(1) } else { q };
+                let swap = (q & Simd::splat(1)).simd_ne(Simd::splat(0));
+                let sign = ((q & Simd::splat(2)) << SIGN_SHIFT).cast::<$uint>();
+                Self::from_bits(swap.select(cos_r, sin).to_bits() ^ sign)
+            }
+
+            #[inline]
+            fn ln_reduced(self) -> (Self, Self, Self, Self) {
+                use consts::$ty::{
+                    EXP_BIAS, LG, MANTISSA_BITS, MANTISSA_MASK, ONE_BITS, SQRT_FRAC_1_2_BITS,
+                    SUBNORMAL_EXP, SUBNORMAL_SCALE,
+                };
+                let subnormal = self.simd_lt(Self::splat($ty::MIN_POSITIVE));
+                let x = subnormal.select(self * Self::splat(SUBNORMAL_SCALE), self);
+                let k_adjust = subnormal.select(Simd::splat(-SUBNORMAL_EXP), Simd::splat(0));
+
+                // Offset the exponent so that mantissas above sqrt(2) carry into it
+                let ix = x.to_bits() + Simd::splat(ONE_BITS - SQRT_FRAC_1_2_BITS);
+                let k = (ix >> MANTISSA_BITS).cast::<$int>() - Simd::splat(EXP_BIAS) + k_adjust;
+                let m = Self::from_bits((ix & Simd::splat(MANTISSA_MASK)) + Simd::splat(SQRT_FRAC_1_2_BITS));
+
+                let f = m - Self::splat(1.0);
+                let s = f / (Self::splat(2.0) + f);
+                let z = s * s;
+                let hfsq = Self::splat(0.5) * f * f;
+                let r = s * (hfsq + z * z.horner(&LG));
+                (k.cast::<$ty>(), f, hfsq, r)
+            }
+
+            #[inline]
+            fn ln_special(self, result: Self) -> Self {
+                let result = self.simd_eq(Self::splat(0.0)).select(Self::splat($ty::NEG_INFINITY), result);
+                let result = self.simd_lt(Self::splat(0.0)).select(Self::splat($ty::NAN), result);
+                (self.is_nan() | self.simd_eq(Self::splat($ty::INFINITY))).select(self, result)
+            }
+
+            #[inline]
+            fn ln_split(f: Self, hfsq: Self, r: Self) -> (Self, Self) {
+                use consts::$ty::LN_HI_MASK;
+                let hi = Self::from_bits((f - hfsq).to_bits() & Simd::splat(LN_HI_MASK));
+                let lo = f - hi - hfsq + r;
+                (hi, lo)
+            }
+        }
+
+        impl<const N: usize> PolyFloat for Simd<$ty, N> {
+            #[inline]
+            fn sin(self) -> Self {
+                let large = self.abs().simd_gt(Self::splat(consts::$ty::TRIG_MAX));
+                let result = self.sin_cos_reduced(false);
+                if large.any() {
+                    // Safety: `self` is a float vector
+                    large.select(unsafe { intrinsics::simd_fsin(self) }, result)
+                } else {
+                    result
+                }
+            }
+
+            #[inline]
+            fn cos(self) -> Self {
+                let large = self.abs().simd_gt(Self::splat(consts::$ty::TRIG_MAX));
+                let result = self.sin_cos_reduced(true);
+                if large.any() {
+                    // Safety: `self` is a float vector
+                    large.select(unsafe { intrinsics::simd_fcos(self) }, result)
+                } else {
+                    result
+                }
+            }
+
+            #[inline]
+            fn exp(self) -> Self {
+                use consts::$ty::{EXP_MAX, EXP_MIN, LN_2_HI, LN_2_LO};
+                let x = clamp(self, EXP_MIN, EXP_MAX);
+                let (k, ki) = (x * Self::splat(core::$ty::consts::LOG2_E)).round_int();
+                let hi = x - k * Self::splat(LN_2_HI);
+                let lo = k * Self::splat(LN_2_LO);
+                Self::exp_reduced(hi, lo).scale(ki)
+            }
+
+            #[inline]
+            fn exp2(self) -> Self {
+                use consts::$ty::{EXP2_MAX, EXP2_MIN};
+                let x = clamp(self, EXP2_MIN, EXP2_MAX);
+                let (k, ki) = x.round_int();
+                let r = (x - k) * Self::splat(core::$ty::consts::LN_2);
+                Self::exp_reduced(r, Self::splat(0.0)).scale(ki)
+            }
+
+            #[inline]
+            fn ln(self) -> Self {
+                use consts::$ty::{LN_2_HI, LN_2_LO};
+                let (k, f, hfsq, r) = self.ln_reduced();
+                let result = r + k * Self::splat(LN_2_LO) - hfsq + f + k * Self::splat(LN_2_HI);
+                self.ln_special(result)
+            }
+
+            #[inline]
+            fn log2(self) -> Self {
+                use consts::$ty::{LOG2_E_HI, LOG2_E_LO};
+                let (k, f, hfsq, r) = self.ln_reduced();
+                let (hi, lo) = Self::ln_split(f, hfsq, r);
+                let val_hi = hi * Self::splat(LOG2_E_HI);
+                let val_lo = (lo + hi) * Self::splat(LOG2_E_LO) + lo * Self::splat(LOG2_E_HI);
+                let w = k + val_hi;
+                let val_lo = val_lo + ((k - w) + val_hi);
+                self.ln_special(val_lo + w)
+            }
+
+            #[inline]
+            fn log10(self) -> Self {
+                use consts::$ty::{LOG10_2_HI, LOG10_2_LO, LOG10_E_HI, LOG10_E_LO};
+                let (k, f, hfsq, r) = self.ln_reduced();
+                let (hi, lo) = Self::ln_split(f, hfsq, r);
+                let val_hi = hi * Self::splat(LOG10_E_HI);
+                let y2 = k * Self::splat(LOG10_2_HI);
+                let val_lo = k * Self::splat(LOG10_2_LO)
+                    + (lo + hi) * Self::splat(LOG10_E_LO)
+                    + lo * Self::splat(LOG10_E_HI);
+                let w = y2 + val_hi;
+                let val_lo = val_lo + ((y2 - w) + val_hi);
+                self.ln_special(val_lo + w)
+            }
+        }
+        )*
+    }
+}
+
+impl_poly! { f32 { int: i32, uint: u32 }, f64 { int: i64, uint: u64 } }
+
+/// Clamps each element to `[min, max]`, preserving NaN.
+#[inline]
+fn clamp<T, const N: usize>(x: Simd<T, N>, min: T, max: T) -> Simd<T, N>
+where
+    T: simd::SimdElement,
+    Simd<T, N>: SimdPartialOrd<Mask = Mask<T::Mask, N>>,
+{
+    let min = Simd::splat(min);
+    let max = Simd::splat(max);
+    let x = x.simd_lt(min).select(min, x);
+    x.simd_gt(max).select(max, x)
+}
+
+/// Constants for reduction and polynomial approximation.
+///
+/// The coefficients and split constants are from the FreeBSD `msun` library (`f64`, and the
+/// `f32` logarithms and exponentials), and the Cephes library (`f32` sine and cosine).
+#[allow(clippy::excessive_precision)]
+mod consts {
+    pub(super) mod f32 {
+        pub(crate) const MANTISSA_BITS: u32 = 23;
+        pub(crate) const EXP_BIAS: i32 = 127;
+        pub(crate) const SIGN_SHIFT: i32 = 30;
+        /// `1.5 * 2^23`
+        pub(crate) const ROUND_MAGIC: f32 = 12582912.0;
+
+        pub(crate) const EXP_MIN: f32 = -104.0;
+        pub(crate) const EXP_MAX: f32 = 89.0;
+        pub(crate) const EXP2_MIN: f32 = -151.0;
+        pub(crate) const EXP2_MAX: f32 = 129.0;
+        pub(crate) const LN_2_HI: f32 = 6.9314575195e-01;
+        pub(crate) const LN_2_LO: f32 = 1.4286067653e-06;
+        pub(crate) const EXP: [f32; 2] = [1.6666625440e-1, -2.7667332906e-3];
+
+        pub(crate) const TRIG_MAX: f32 = 8192.0;
+        pub(crate) const FRAC_2_PI: f32 = core::f32::consts::FRAC_2_PI;
+        /// `pi / 2` split into parts with 11 significant bits, so that multiplying by
+        /// any quadrant below `TRIG_MAX` is exact, followed by the rounded remainder.
+        pub(crate) const FRAC_PI_2_PARTS: [f32; 5] = [
+            1.5703125,
+            0.0004837512969970703,
+            7.549533620476723e-8,
+            2.5632829192545614e-12,
+            6.123234262925839e-17,
+        ];
+        pub(crate) const SIN: [f32; 3] = [-1.6666654611e-1, 8.3321608736e-3, -1.9515295891e-4];
+        pub(crate) const COS: [f32; 3] = [
+            4.166664568298827e-2,
+            -1.388731625493765e-3,
+            2.443315711809948e-5,
+        ];
+
+        /// `2^25`
+        pub(crate) const SUBNORMAL_SCALE: f32 = 33554432.0;
+        pub(crate) const SUBNORMAL_EXP: i32 = 25;
+        pub(crate) const ONE_BITS: u32 = 0x3f800000;
+        pub(crate) const SQRT_FRAC_1_2_BITS: u32 = 0x3f3504f3;
+        pub(crate) const MANTISSA_MASK: u32 = 0x007fffff;
+        pub(crate) const LN_HI_MASK: u32 = 0xfffff000;
+        pub(crate) const LG: [f32; 4] = [
+            0.6666666269302368,
+            0.40000972151756287,
+            0.2849878668785095,
+            0.24279078841209412,
+        ];
+        pub(crate) const LOG2_E_HI: f32 = 1.4428710938e+00;
+        pub(crate) const LOG2_E_LO: f32 = -1.7605285393e-04;
+        pub(crate) const LOG10_E_HI: f32 = 4.3432617188e-01;
+        pub(crate) const LOG10_E_LO: f32 = -3.1689971365e-05;
+        pub(crate) const LOG10_2_HI: f32 = 3.0102920532e-01;
+        pub(crate) const LOG10_2_LO: f32 = 7.9034151668e-07;
+    }
+
+    pub(super) mod f64 {
+        pub(crate) const MANTISSA_BITS: u64 = 52;
+        pub(crate) const EXP_BIAS: i64 = 1023;
+        pub(crate) const SIGN_SHIFT: i64 = 62;
+        /// `1.5 * 2^52`
+        pub(crate) const ROUND_MAGIC: f64 = 6755399441055744.0;
+
+        pub(crate) const EXP_MIN: f64 = -746.0;
+        pub(crate) const EXP_MAX: f64 = 710.0;
+        pub(crate) const EXP2_MIN: f64 = -1076.0;
+        pub(crate) const EXP2_MAX: f64 = 1025.0;
+        pub(crate) const LN_2_HI: f64 = 6.93147180369123816490e-01;
+        pub(crate) const LN_2_LO: f64 = 1.90821492927058770002e-10;
+        pub(crate) const EXP: [f64; 5] = [
+            1.66666666666666019037e-01,
+            -2.77777777770155933842e-03,
+            6.61375632143793436117e-05,
+            -1.65339022054652515390e-06,
+            4.13813679705723846039e-08,
+        ];
+
+        pub(crate) const TRIG_MAX: f64 = 1048576.0;
+        pub(crate) const FRAC_2_PI: f64 = core::f64::consts::FRAC_2_PI;
+        /// `pi / 2` split into parts with 33 significant bits, so that multiplying by
+        /// any quadrant below `TRIG_MAX` is exact, followed by the rounded remainder.
+        pub(crate) const FRAC_PI_2_PARTS: [f64; 4] = [
+            1.57079632673412561417e+00,
+            6.07710050630396597660e-11,
+            2.02226624871116645580e-21,
+            8.47842766036889956997e-32,
+        ];
+        pub(crate) const SIN: [f64; 6] = [
+            -1.66666666666666324348e-01,
+            8.33333333332248946124e-03,
+            -1.98412698298579493134e-04,
+            2.75573137070700676789e-06,
+            -2.50507602534068634195e-08,
+            1.58969099521155010221e-10,
+        ];
+        pub(crate) const COS: [f64; 6] = [
+            4.16666666666666019037e-02,
+            -1.38888888888741095749e-03,
+            2.48015872894767294178e-05,
+            -2.75573143513906633035e-07,
+            2.08757232129817482790e-09,
+            -1.13596475577881948265e-11,
+        ];
+
+        /// `2^54`
+        pub(crate) const SUBNORMAL_SCALE: f64 = 18014398509481984.0;
+        pub(crate) const SUBNORMAL_EXP: i64 = 54;
+        pub(crate) const ONE_BITS: u64 = 0x3ff0000000000000;
+        pub(crate) const SQRT_FRAC_1_2_BITS: u64 = 0x3fe6a09e00000000;
+        pub(crate) const MANTISSA_MASK: u64 = 0x000fffffffffffff;
+        pub(crate) const LN_HI_MASK: u64 = 0xffffffff00000000;
+        pub(crate) const LG: [f64; 7] = [
+            6.666666666666735130e-01,
+            3.999999999940941908e-01,
+            2.857142874366239149e-01,
+            2.222219843214978396e-01,
+            1.818357216161805012e-01,
+            1.531383769920937332e-01,
+            1.479819860511658591e-01,
+        ];
+        pub(crate) const LOG2_E_HI: f64 = 1.44269504072144627571e+00;
+        pub(crate) const LOG2_E_LO: f64 = 1.67517131648865118353e-10;
+        pub(crate) const LOG10_E_HI: f64 = 4.34294481878168880939e-01;
+        pub(crate) const LOG10_E_LO: f64 = 2.50829467116452752298e-11;
+        pub(crate) const LOG10_2_HI: f64 = 3.01029995663611771306e-01;
+        pub(crate) const LOG10_2_LO: f64 = 3.69423907715893078616e-13;
+    }
+}
@@ -37,15 +37,15 @@ macro_rules! unary_approx_test {
     }
 }
 
-macro_rules! ternary_test {
+macro_rules! binary_test {
     { $scalar:tt, $($func:tt),+ } => {
         test_helpers::test_lanes! {
             $(
             fn $func<const LANES: usize>() {
-                test_helpers::test_ternary_elementwise(
+                test_helpers::test_binary_elementwise(
                     &core_simd::simd::Simd::<$scalar, LANES>::$func,
                     &$scalar::$func,
-                    &|_, _, _| true,
+                    &|_, _| true,
                 )
             }
             )*
@@ -58,8 +58,10 @@ macro_rules! impl_tests {
         mod $scalar {
             use std_float::StdFloat;
 
-            unary_test! { $scalar, sqrt, ceil, floor, round, trunc, round_ties_even }
-            ternary_test! { $scalar, mul_add }
+            unary_test! { $scalar, ceil, floor, round, trunc, round_ties_even }
+
+            unary_test! { $scalar, exp_m1, ln_1p, cbrt, tan, asin, acos, atan, sinh, cosh, tanh }
+            binary_test! { $scalar, powf, hypot, atan2 }
 
             // https://github.com/rust-lang/miri/issues/3555
             unary_approx_test! { $scalar, sin, cos, exp, exp2, ln, log2, log10 }
@@ -77,6 +79,59 @@ macro_rules! impl_tests {
                 }
             }
 
+            // `mul_add` and `sqrt` are provided by the `SimdFloat` supertrait
+            test_helpers::test_lanes! {
+                fn sqrt<const LANES: usize>() {
+                    fn sqrt<V: StdFloat>(x: V) -> V {
+                        x.sqrt()
+                    }
+                    test_helpers::test_unary_elementwise(
+                        &sqrt::<core_simd::simd::Simd<$scalar, LANES>>,
+                        &$scalar::sqrt,
+                        &|_| true,
+                    )
+                }
+
+                fn mul_add<const LANES: usize>() {
+                    fn mul_add<V: StdFloat>(x: V, a: V, b: V) -> V {
+                        x.mul_add(a, b)
+                    }
+                    test_helpers::test_ternary_elementwise(
+                        &mul_add::<core_simd::simd::Simd<$scalar, LANES>>,
+                        &$scalar::mul_add,
+                        &|_, _, _| true,
+                    )
+                }
+            }
+
+            test_helpers::test_lanes! {
+                fn powi<const LANES: usize>() {
+                    for n in [-3, -1, 0, 1, 2, 5] {
+                        test_helpers::test_unary_elementwise(
+                            &|x: core_simd::simd::Simd<$scalar, LANES>| x.powi(n),
+                            &|x: $scalar| x.powi(n),
+                            &|_| true,
+                        )
+                    }
+                }
+
+                // https://github.com/rust-lang/miri/issues/3555
+                fn sin_cos<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise_approx(
+                        &|x: core_simd::simd::Simd<$scalar, LANES>| x.sin_cos().0,
+                        &|x: $scalar| x.sin_cos().0,
+                        &|_| true,
+                        16,
+                    );
+                    test_helpers::test_unary_elementwise_approx(
+                        &|x: core_simd::simd::Simd<$scalar, LANES>| x.sin_cos().1,
+                        &|x: $scalar| x.sin_cos().1,
+                        &|_| true,
+                        16,
+                    );
+                }
+            }
+
             test_helpers::test_lanes! {
                 fn fract<const LANES: usize>() {
                     test_helpers::test_unary_elementwise_flush_subnormals(
@@ -0,0 +1,104 @@
+#![feature(portable_simd)]
+#![expect(internal_features)]
+#![feature(cfg_target_has_reliable_f16_f128)]
+#![cfg_attr(target_has_reliable_f128_math, feature(f128))]
+
+use core_simd::simd::Simd;
+use std_float::PolyFloat;
+
+macro_rules! impl_tests {
+    { $($scalar:tt $args:tt),+; $funcs:tt } => {
+        $(impl_tests! { @type $scalar $args $funcs })+
+    };
+    {
+        @type $scalar:tt { bits: $bits:ty, int: $int:ty, step: $step:expr, wide: $wide:ty }
+        { $($func:tt: $ulps:literal),+ }
+    } => {
+        mod $scalar {
+            use super::*;
+
+            /// The type reference results are computed in, wider than `$scalar` where possible.
+            type Wide = $wide;
+
+            test_helpers::test_lanes! {
+                $(
+                fn $func<const LANES: usize>() {
+                    test_helpers::test_unary_elementwise_approx(
+                        &<Simd<$scalar, LANES> as PolyFloat>::$func,
+                        &|x: $scalar| (x as Wide).$func() as $scalar,
+                        &|_| true,
+                        $ulps,
+                    )
+                }
+                )*
+            }
+
+            /// Distance between two floats in ULPs, treating all NaNs as equal.
+            fn ulps(a: $scalar, b: $scalar) -> u64 {
+                if a.is_nan() && b.is_nan() {
+                    return 0;
+                }
+                if a.is_nan() || b.is_nan() {
+                    return u64::MAX;
+                }
+                let key = |x: $scalar| {
+                    let i = x.to_bits() as $int;
+                    if i < 0 { <$int>::MIN - i } else { i }
+                };
+                (key(a) as i128 - key(b) as i128).unsigned_abs() as u64
+            }
+
+            /// Checks `vector` against `scalar` for inputs spread over every binade.
+            fn sweep(
+                vector: fn(Simd<$scalar, 8>) -> Simd<$scalar, 8>,
+                scalar: fn($scalar) -> $scalar,
+                max_ulps: u64,
+            ) {
+                let mut worst = (0, 0.0);
+                let mut bits: $bits = 0;
+                loop {
+                    let input = Simd::from_array(core::array::from_fn(|i| {
+                        <$scalar>::from_bits(bits.wrapping_add(i as $bits * ($step / 8)))
+                    }));
+                    for (x, y) in input.to_array().into_iter().zip(vector(input).to_array()) {
+                        let error = ulps(y, scalar(x));
+                        if error > worst.0 {
+                            worst = (error, x);
+                        }
+                    }
+                    bits = match bits.checked_add($step) {
+                        Some(bits) => bits,
+                        None => break,
+                    };
+                }
+                assert!(worst.0 <= max_ulps, "error of {} ULPs for input {:?}", worst.0, worst.1);
+            }
+
+            mod sweep {
+                $(
+                #[test]
+                fn $func() {
+                    super::sweep(
+                        std_float::PolyFloat::$func,
+                        |x| (x as super::Wide).$func() as $scalar,
+                        $ulps,
+                    );
+                }
+                )*
+            }
+        }
+    }
+}
+
+/// A type for computing `f64` reference results, where `f128` math is available.
+#[cfg(target_has_reliable_f128_math)]
+type WideF64 = f128;
+#[cfg(not(target_has_reliable_f128_math))]
+type WideF64 = f64;
+
+impl_tests! {
+    f32 { bits: u32, int: i32, step: 1 << 15, wide: f64 },
+    f64 { bits: u64, int: i64, step: 1 << 47, wide: WideF64 };
+    // The bounds documented on `PolyFloat`
+    { sin: 2, cos: 2, exp: 1, exp2: 1, ln: 1, log2: 1, log10: 2 }
+}